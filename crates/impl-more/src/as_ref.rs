@@ -3,6 +3,10 @@
 /// The first argument is that of the struct to create the impl for and the second is the type to
 /// produce a reference for.
 ///
+/// The struct can be named using any type path. Using the `Foo<T>` shorthand declares all the
+/// listed type arguments as parameters of the impl; use the `<...> in Foo<...>` form for
+/// anything else.
+///
 /// # Examples
 /// With a newtype struct:
 /// ```
@@ -23,23 +27,75 @@
 /// let foo = Foo { inner: "bar".to_owned() };
 /// assert_eq!(foo.as_ref().as_str(), "bar");
 /// ```
+///
+/// With a path-qualified type:
+/// ```
+/// use impl_more::impl_as_ref;
+///
+/// mod model {
+///     pub struct UserId(pub String);
+/// }
+///
+/// impl_as_ref!(model::UserId => String);
+/// let id = model::UserId("bar".to_owned());
+/// assert_eq!(id.as_ref(), "bar");
+/// ```
 #[macro_export]
 macro_rules! impl_as_ref {
-    ($this:ident $(<$($generic:ident),+>)? => $inner:ty) => {
-        impl $(<$($generic),+>)? ::core::convert::AsRef<$inner> for $this $(<$($generic),+>)? {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_as_ref [$(#[$attr])*] [=>] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $($segment:ident)::+ <$($param:tt),+> => $($rest:tt)*) => {
+        $crate::impl_as_ref!(
+            @impl [$(#[$attr])*] [$($param),+] [$($segment)::+ <$($param),+>] [] => $($rest)*
+        );
+    };
+
+    ($(#[$attr:meta])* $this:ty => $($rest:tt)*) => {
+        $crate::impl_as_ref!(@impl [$(#[$attr])*] [] [$this] [] => $($rest)*);
+    };
+
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*] => $inner:ty) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::AsRef<$inner> for $this where $($where)* {
+            #[inline]
             fn as_ref(&self) -> &$inner {
                 &self.0
             }
         }
     };
 
-    ($this:ident $(<$($generic:ident),+>)? => $field:ident : $inner:ty) => {
-        impl $(<$($generic),+>)? ::core::convert::AsRef<$inner> for $this $(<$($generic),+>)? {
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*]
+        => $($field:tt).+ : $inner:ty
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::AsRef<$inner> for $this where $($where)* {
+            #[inline]
             fn as_ref(&self) -> &$inner {
-                &self.$field
+                &self.$($field).+
             }
         }
     };
+
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!("missing target type; expected `impl_as_ref!(Type => Target)`");
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!("missing target type; expected `impl_as_ref!(Type => Target)`");
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(
+            "expected `impl_as_ref!(Type => Target)` or `impl_as_ref!(Type => field: Target)`"
+        );
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_as_ref $($input)*);
+    };
 }
 
 /// Implement [`AsRef`] by forwarding to a field's implementation.
@@ -69,39 +125,68 @@ macro_rules! impl_as_ref {
 /// let foo = Foo { inner: vec![1, 2, 3] };
 /// assert_eq!(foo.as_ref(), &[1, 2, 3]);
 /// ```
+///
+/// With a newtype struct over borrowed data:
+/// ```
+/// use impl_more::forward_as_ref;
+///
+/// struct View<'a, T>(&'a [T]);
+/// forward_as_ref!(<'a, T> in View<'a, T> => [T]);
+///
+/// let view = View(&[1, 2, 3]);
+/// assert_eq!(view.as_ref(), &[1, 2, 3]);
+/// ```
 #[macro_export]
 macro_rules! forward_as_ref {
-    (<$($generic:ident),+> in $this:ty => $target:ty) => {
-        impl <$($generic),+> ::core::convert::AsRef<$target> for $this {
-            fn as_ref(&self) -> &$target {
-                ::core::convert::AsRef::<$target>::as_ref(&self.0)
-            }
-        }
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(forward_as_ref [$(#[$attr])*] [=>] <$($rest)*);
     };
 
-    (<$($generic:ident),+> in $this:ty => $field:ident : $target:ty) => {
-        impl <$($generic),+> ::core::convert::AsRef<$target> for $this {
-            fn as_ref(&self) -> &$target {
-                ::core::convert::AsRef::<$target>::as_ref(&self.$field)
-            }
-        }
+    ($(#[$attr:meta])* $this:ty => $($rest:tt)*) => {
+        $crate::forward_as_ref!(@impl [$(#[$attr])*] [] [$this] [] => $($rest)*);
     };
 
-    ($this:ty => $target:ty) => {
-        impl ::core::convert::AsRef<$target> for $this {
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*] => $target:ty) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::AsRef<$target> for $this where $($where)* {
+            #[inline]
             fn as_ref(&self) -> &$target {
                 ::core::convert::AsRef::<$target>::as_ref(&self.0)
             }
         }
     };
 
-    ($this:ty => $field:ident : $target:ty) => {
-        impl ::core::convert::AsRef<$target> for $this {
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*]
+        => $($field:tt).+ : $target:ty
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::AsRef<$target> for $this where $($where)* {
+            #[inline]
             fn as_ref(&self) -> &$target {
-                ::core::convert::AsRef::<$target>::as_ref(&self.$field)
+                ::core::convert::AsRef::<$target>::as_ref(&self.$($field).+)
             }
         }
     };
+
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!("missing target type; expected `forward_as_ref!(Type => Target)`");
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!("missing target type; expected `forward_as_ref!(Type => Target)`");
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `forward_as_ref!(Type => Target)` or ",
+            "`forward_as_ref!(Type => field: Target)`",
+        ));
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(forward_as_ref $($input)*);
+    };
 }
 
 /// Implement [`AsMut`] for a struct.
@@ -109,6 +194,8 @@ macro_rules! forward_as_ref {
 /// The first argument is that of the struct to create the impl for and the second is the type to
 /// produce a reference for.
 ///
+/// This macro has the same type path and type parameter support as [`impl_as_ref`].
+///
 /// # Examples
 /// With a newtype struct:
 /// ```
@@ -139,23 +226,64 @@ macro_rules! forward_as_ref {
 ///
 /// assert_eq!(foo.as_ref(), "bar!");
 /// ```
+///
+/// [`impl_as_ref`]: crate::impl_as_ref
 #[macro_export]
 macro_rules! impl_as_mut {
-    ($this:ident $(<$($generic:ident),+>)? => $inner:ty) => {
-        impl $(<$($generic),+>)? ::core::convert::AsMut<$inner> for $this $(<$($generic),+>)? {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_as_mut [$(#[$attr])*] [=>] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $($segment:ident)::+ <$($param:tt),+> => $($rest:tt)*) => {
+        $crate::impl_as_mut!(
+            @impl [$(#[$attr])*] [$($param),+] [$($segment)::+ <$($param),+>] [] => $($rest)*
+        );
+    };
+
+    ($(#[$attr:meta])* $this:ty => $($rest:tt)*) => {
+        $crate::impl_as_mut!(@impl [$(#[$attr])*] [] [$this] [] => $($rest)*);
+    };
+
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*] => $inner:ty) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::AsMut<$inner> for $this where $($where)* {
+            #[inline]
             fn as_mut(&mut self) -> &mut $inner {
                 &mut self.0
             }
         }
     };
 
-    ($this:ident $(<$($generic:ident),+>)? => $field:ident : $inner:ty) => {
-        impl $(<$($generic),+>)? ::core::convert::AsMut<$inner> for $this $(<$($generic),+>)? {
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*]
+        => $($field:tt).+ : $inner:ty
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::AsMut<$inner> for $this where $($where)* {
+            #[inline]
             fn as_mut(&mut self) -> &mut $inner {
-                &mut self.$field
+                &mut self.$($field).+
             }
         }
     };
+
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!("missing target type; expected `impl_as_mut!(Type => Target)`");
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!("missing target type; expected `impl_as_mut!(Type => Target)`");
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(
+            "expected `impl_as_mut!(Type => Target)` or `impl_as_mut!(Type => field: Target)`"
+        );
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_as_mut $($input)*);
+    };
 }
 
 /// Implement [`AsMut`] by forwarding to a field's implementation.
@@ -191,37 +319,55 @@ macro_rules! impl_as_mut {
 /// ```
 #[macro_export]
 macro_rules! forward_as_mut {
-    (<$($generic:ident),+> in $this:ty => $target:ty) => {
-        impl <$($generic),+> ::core::convert::AsMut<$target> for $this {
-            fn as_mut(&mut self) -> &mut $target {
-                ::core::convert::AsMut::<$target>::as_mut(&mut self.0)
-            }
-        }
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(forward_as_mut [$(#[$attr])*] [=>] <$($rest)*);
     };
 
-    (<$($generic:ident),+> in $this:ty => $field:ident : $target:ty) => {
-        impl <$($generic),+> ::core::convert::AsMut<$target> for $this {
-            fn as_mut(&mut self) -> &mut $target {
-                ::core::convert::AsMut::<$target>::as_mut(&mut self.$field)
-            }
-        }
+    ($(#[$attr:meta])* $this:ty => $($rest:tt)*) => {
+        $crate::forward_as_mut!(@impl [$(#[$attr])*] [] [$this] [] => $($rest)*);
     };
 
-    ($this:ty => $target:ty) => {
-        impl ::core::convert::AsMut<$target> for $this {
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*] => $target:ty) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::AsMut<$target> for $this where $($where)* {
+            #[inline]
             fn as_mut(&mut self) -> &mut $target {
                 ::core::convert::AsMut::<$target>::as_mut(&mut self.0)
             }
         }
     };
 
-    ($this:ty => $field:ident : $target:ty) => {
-        impl ::core::convert::AsMut<$target> for $this {
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*]
+        => $($field:tt).+ : $target:ty
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::AsMut<$target> for $this where $($where)* {
+            #[inline]
             fn as_mut(&mut self) -> &mut $target {
-                ::core::convert::AsMut::<$target>::as_mut(&mut self.$field)
+                ::core::convert::AsMut::<$target>::as_mut(&mut self.$($field).+)
             }
         }
     };
+
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!("missing target type; expected `forward_as_mut!(Type => Target)`");
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!("missing target type; expected `forward_as_mut!(Type => Target)`");
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `forward_as_mut!(Type => Target)` or ",
+            "`forward_as_mut!(Type => field: Target)`",
+        ));
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(forward_as_mut $($input)*);
+    };
 }
 
 /// Implement [`AsRef`] and [`AsMut`] by forwarding to a field's implementations.
@@ -244,24 +390,40 @@ macro_rules! forward_as_mut {
 /// [`forward_as_ref`]: crate::forward_as_ref
 #[macro_export]
 macro_rules! forward_as_ref_and_mut {
-    (<$($generic:ident),+> in $this:ty => $target:ty) => {
-        $crate::forward_as_ref!(<$($generic),+> in $this => $target);
-        $crate::forward_as_mut!(<$($generic),+> in $this => $target);
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(forward_as_ref_and_mut [$(#[$attr])*] [=>] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $this:ty => $($rest:tt)*) => {
+        $crate::forward_as_ref_and_mut!(@impl [$(#[$attr])*] [] [$this] [] => $($rest)*);
+    };
+
+    (@impl $ctx:tt $generics:tt $this:tt $where:tt $($rest:tt)*) => {
+        $crate::forward_as_ref!(@impl $ctx $generics $this $where $($rest)*);
+        $crate::forward_as_mut!(@impl $ctx $generics $this $where $($rest)*);
     };
 
-    (<$($generic:ident),+> in $this:ty => $field:ident : $target:ty) => {
-        $crate::forward_as_ref!(<$($generic),+> in $this => $field: $target);
-        $crate::forward_as_mut!(<$($generic),+> in $this => $field: $target);
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!(
+            "missing target type; expected `forward_as_ref_and_mut!(Type => Target)`"
+        );
     };
 
-    ($this:ty => $target:ty) => {
-        $crate::forward_as_ref!($this => $target);
-        $crate::forward_as_mut!($this => $target);
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!(
+            "missing target type; expected `forward_as_ref_and_mut!(Type => Target)`"
+        );
     };
 
-    ($this:ty => $field:ident : $target:ty) => {
-        $crate::forward_as_ref!($this => $field: $target);
-        $crate::forward_as_mut!($this => $field: $target);
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `forward_as_ref_and_mut!(Type => Target)` or ",
+            "`forward_as_ref_and_mut!(Type => field: Target)`",
+        ));
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(forward_as_ref_and_mut $($input)*);
     };
 }
 
@@ -282,9 +444,62 @@ mod tests {
     }
     forward_as_ref_and_mut!(<T> in GenericNamed<T> => inner: [T]);
 
+    struct Bounded<T: Clone>(Vec<T>);
+    impl_as_ref!(<T> in Bounded<T> where T: Clone => Vec<T>);
+    forward_as_ref!(<T: Clone> in Bounded<T> => [T]);
+
+    struct Borrowed<'a>(&'a mut String);
+    impl_as_ref!(Borrowed<'a> => &'a mut String);
+    forward_as_ref!(<'a> in Borrowed<'a> => str);
+    forward_as_mut!(<'a> in Borrowed<'a> => str);
+
+    struct View<'a, T>(&'a [T]);
+    forward_as_ref!(<'a, T> in View<'a, T> => [T]);
+
+    struct Frame<const N: usize>([u8; N]);
+    impl_as_ref!(<const N: usize> in Frame<N> => [u8; N]);
+    forward_as_ref_and_mut!(<const N: usize> in Frame<N> => [u8]);
+
+    struct Tagged<M>(core::marker::PhantomData<M>, String);
+    impl_as_ref!(<M> in Tagged<M> => 1: String);
+    impl_as_mut!(<M> in Tagged<M> => 1: String);
+    forward_as_ref!(<M> in Tagged<M> => 1: str);
+
+    struct Inner {
+        buf: Vec<u8>,
+    }
+    struct Outer {
+        inner: Inner,
+    }
+    forward_as_ref_and_mut!(Outer => inner.buf: [u8]);
+
+    mod model {
+        use alloc::string::String;
+
+        pub(super) struct UserId(pub(super) String);
+        pub(super) struct Wrapper<T>(pub(super) T);
+    }
+    impl_as_ref!(model::UserId => String);
+    impl_as_mut!(self::model::UserId => String);
+    impl_as_ref!(model::Wrapper<T> => T);
+    impl_as_mut!(self::model::Wrapper<T> => T);
+
+    struct Aliased(u8);
+    type Alias = Aliased;
+    impl_as_ref!(Alias => u8);
+
     static_assertions::assert_impl_all!(Newtype: AsRef<str>, AsMut<str>);
     static_assertions::assert_impl_all!(Generic<usize>: AsRef<[usize]>, AsMut<[usize]>);
     static_assertions::assert_impl_all!(GenericNamed<usize>: AsRef<[usize]>, AsMut<[usize]>);
+    static_assertions::assert_impl_all!(Bounded<usize>: AsRef<Vec<usize>>, AsRef<[usize]>);
+    static_assertions::assert_impl_all!(Borrowed<'static>: AsRef<str>, AsMut<str>);
+    static_assertions::assert_impl_all!(View<'static, usize>: AsRef<[usize]>);
+    static_assertions::assert_impl_all!(Frame<4>: AsRef<[u8; 4]>, AsRef<[u8]>, AsMut<[u8]>);
+    static_assertions::assert_impl_all!(Tagged<()>: AsRef<String>, AsMut<String>, AsRef<str>);
+    static_assertions::assert_impl_all!(Outer: AsRef<[u8]>, AsMut<[u8]>);
+    static_assertions::assert_impl_all!(model::UserId: AsRef<String>, AsMut<String>);
+    static_assertions::assert_impl_all!(model::Wrapper<u16>: AsRef<u16>, AsMut<u16>);
+    static_assertions::assert_impl_all!(Alias: AsRef<u8>);
 
     #[test]
     fn forwards_newtype() {
@@ -302,6 +517,31 @@ mod tests {
         assert_eq!(AsRef::<[usize]>::as_ref(&value), &[3, 2, 1]);
     }
 
+    #[test]
+    fn forwards_borrowed_newtype() {
+        let mut inner = String::from("hello");
+        let mut value = Borrowed(&mut inner);
+        AsMut::<str>::as_mut(&mut value).make_ascii_uppercase();
+        assert_eq!(AsRef::<str>::as_ref(&value), "HELLO");
+
+        let items = [1, 2, 3];
+        let view = View(&items);
+        assert_eq!(AsRef::<[usize]>::as_ref(&view), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn forwards_field_paths() {
+        let mut value = Tagged::<()>(core::marker::PhantomData, "hello".into());
+        AsMut::<String>::as_mut(&mut value).push('!');
+        assert_eq!(AsRef::<str>::as_ref(&value), "hello!");
+
+        let mut value = Outer {
+            inner: Inner { buf: vec![1, 2, 3] },
+        };
+        AsMut::<[u8]>::as_mut(&mut value).reverse();
+        assert_eq!(AsRef::<[u8]>::as_ref(&value), &[3, 2, 1]);
+    }
+
     #[test]
     fn forwards_generic_named_field() {
         let mut value = GenericNamed {
@@ -0,0 +1,424 @@
+/// Invokes several of this crate's macros in one block.
+///
+/// Each entry names a macro from this crate followed by its (usually batched) input. This allows a
+/// whole table of wrapper types to be declared together.
+///
+/// # Examples
+///
+/// ```
+/// struct UserId(String);
+/// struct OrgId(String);
+/// struct Seq(u64);
+///
+/// impl_more::impls! {
+///     impl_from! { String => UserId; String => OrgId; u64 => Seq }
+///     impl_into! { UserId => String; OrgId => String; Seq => u64 }
+///     impl_deref! { UserId => String; OrgId => String }
+///     forward_display! { UserId; OrgId; Seq }
+///     forward_from_str! { UserId => String; OrgId => String; Seq => u64 }
+/// }
+///
+/// let id: UserId = "alice".parse().unwrap();
+/// assert_eq!(id.len(), 5);
+/// assert_eq!(Seq::from(42).to_string(), "42");
+/// ```
+#[macro_export]
+macro_rules! impls {
+    ($($macro:ident ! $input:tt $(;)?)*) => {
+        $($crate::impls!(@invoke $macro $input);)*
+    };
+
+    (@invoke $macro:ident { $($input:tt)* }) => {
+        $crate::$macro!($($input)*);
+    };
+
+    (@invoke $macro:ident ( $($input:tt)* )) => {
+        $crate::$macro!($($input)*);
+    };
+
+    (@invoke $macro:ident [ $($input:tt)* ]) => {
+        $crate::$macro!($($input)*);
+    };
+
+    ($($input:tt)*) => {
+        ::core::compile_error!("expected entries of the form `macro_name! { ... }`");
+    };
+}
+
+/// Splits a batch of `;`-separated macro inputs and invokes the macro once per entry.
+///
+/// Every macro in this crate delegates to this helper as its final rule, so input that does not
+/// match a single invocation is treated as a batch. Input without any delimiters is passed back to
+/// the macro's `@invalid` rules, which report the expected syntax.
+///
+/// Large batches are first cut into runs that end in a delimiter: each step skips thirty-two tokens
+/// and cuts after the first delimiter among the next eight, so recursion depth grows with the
+/// length of the input rather than with the number of entries. Each run is then split one entry at
+/// a time, checking several tokens per step.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __batch {
+    ($macro:ident $($input:tt)*) => {
+        $crate::__batch!(@skip $macro [] [] $($input)*);
+    };
+
+    (
+        @skip $macro:ident $seen:tt [$($pending:tt)*]
+        $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt
+        $s9:tt $s10:tt $s11:tt $s12:tt $s13:tt $s14:tt $s15:tt $s16:tt
+        $s17:tt $s18:tt $s19:tt $s20:tt $s21:tt $s22:tt $s23:tt $s24:tt
+        $s25:tt $s26:tt $s27:tt $s28:tt $s29:tt $s30:tt $s31:tt $s32:tt
+        ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(
+            @split $macro [] [] $($pending)*
+            $s1 $s2 $s3 $s4 $s5 $s6 $s7 $s8
+            $s9 $s10 $s11 $s12 $s13 $s14 $s15 $s16
+            $s17 $s18 $s19 $s20 $s21 $s22 $s23 $s24
+            $s25 $s26 $s27 $s28 $s29 $s30 $s31 $s32
+            ;
+        );
+        $crate::__batch!(@skip $macro [;] [] $($rest)*);
+    };
+
+    (
+        @skip $macro:ident $seen:tt [$($pending:tt)*]
+        $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt
+        $s9:tt $s10:tt $s11:tt $s12:tt $s13:tt $s14:tt $s15:tt $s16:tt
+        $s17:tt $s18:tt $s19:tt $s20:tt $s21:tt $s22:tt $s23:tt $s24:tt
+        $s25:tt $s26:tt $s27:tt $s28:tt $s29:tt $s30:tt $s31:tt $s32:tt
+        $t1:tt ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(
+            @split $macro [] [] $($pending)*
+            $s1 $s2 $s3 $s4 $s5 $s6 $s7 $s8
+            $s9 $s10 $s11 $s12 $s13 $s14 $s15 $s16
+            $s17 $s18 $s19 $s20 $s21 $s22 $s23 $s24
+            $s25 $s26 $s27 $s28 $s29 $s30 $s31 $s32
+            $t1 ;
+        );
+        $crate::__batch!(@skip $macro [;] [] $($rest)*);
+    };
+
+    (
+        @skip $macro:ident $seen:tt [$($pending:tt)*]
+        $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt
+        $s9:tt $s10:tt $s11:tt $s12:tt $s13:tt $s14:tt $s15:tt $s16:tt
+        $s17:tt $s18:tt $s19:tt $s20:tt $s21:tt $s22:tt $s23:tt $s24:tt
+        $s25:tt $s26:tt $s27:tt $s28:tt $s29:tt $s30:tt $s31:tt $s32:tt
+        $t1:tt $t2:tt ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(
+            @split $macro [] [] $($pending)*
+            $s1 $s2 $s3 $s4 $s5 $s6 $s7 $s8
+            $s9 $s10 $s11 $s12 $s13 $s14 $s15 $s16
+            $s17 $s18 $s19 $s20 $s21 $s22 $s23 $s24
+            $s25 $s26 $s27 $s28 $s29 $s30 $s31 $s32
+            $t1 $t2 ;
+        );
+        $crate::__batch!(@skip $macro [;] [] $($rest)*);
+    };
+
+    (
+        @skip $macro:ident $seen:tt [$($pending:tt)*]
+        $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt
+        $s9:tt $s10:tt $s11:tt $s12:tt $s13:tt $s14:tt $s15:tt $s16:tt
+        $s17:tt $s18:tt $s19:tt $s20:tt $s21:tt $s22:tt $s23:tt $s24:tt
+        $s25:tt $s26:tt $s27:tt $s28:tt $s29:tt $s30:tt $s31:tt $s32:tt
+        $t1:tt $t2:tt $t3:tt ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(
+            @split $macro [] [] $($pending)*
+            $s1 $s2 $s3 $s4 $s5 $s6 $s7 $s8
+            $s9 $s10 $s11 $s12 $s13 $s14 $s15 $s16
+            $s17 $s18 $s19 $s20 $s21 $s22 $s23 $s24
+            $s25 $s26 $s27 $s28 $s29 $s30 $s31 $s32
+            $t1 $t2 $t3 ;
+        );
+        $crate::__batch!(@skip $macro [;] [] $($rest)*);
+    };
+
+    (
+        @skip $macro:ident $seen:tt [$($pending:tt)*]
+        $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt
+        $s9:tt $s10:tt $s11:tt $s12:tt $s13:tt $s14:tt $s15:tt $s16:tt
+        $s17:tt $s18:tt $s19:tt $s20:tt $s21:tt $s22:tt $s23:tt $s24:tt
+        $s25:tt $s26:tt $s27:tt $s28:tt $s29:tt $s30:tt $s31:tt $s32:tt
+        $t1:tt $t2:tt $t3:tt $t4:tt ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(
+            @split $macro [] [] $($pending)*
+            $s1 $s2 $s3 $s4 $s5 $s6 $s7 $s8
+            $s9 $s10 $s11 $s12 $s13 $s14 $s15 $s16
+            $s17 $s18 $s19 $s20 $s21 $s22 $s23 $s24
+            $s25 $s26 $s27 $s28 $s29 $s30 $s31 $s32
+            $t1 $t2 $t3 $t4 ;
+        );
+        $crate::__batch!(@skip $macro [;] [] $($rest)*);
+    };
+
+    (
+        @skip $macro:ident $seen:tt [$($pending:tt)*]
+        $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt
+        $s9:tt $s10:tt $s11:tt $s12:tt $s13:tt $s14:tt $s15:tt $s16:tt
+        $s17:tt $s18:tt $s19:tt $s20:tt $s21:tt $s22:tt $s23:tt $s24:tt
+        $s25:tt $s26:tt $s27:tt $s28:tt $s29:tt $s30:tt $s31:tt $s32:tt
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(
+            @split $macro [] [] $($pending)*
+            $s1 $s2 $s3 $s4 $s5 $s6 $s7 $s8
+            $s9 $s10 $s11 $s12 $s13 $s14 $s15 $s16
+            $s17 $s18 $s19 $s20 $s21 $s22 $s23 $s24
+            $s25 $s26 $s27 $s28 $s29 $s30 $s31 $s32
+            $t1 $t2 $t3 $t4 $t5 ;
+        );
+        $crate::__batch!(@skip $macro [;] [] $($rest)*);
+    };
+
+    (
+        @skip $macro:ident $seen:tt [$($pending:tt)*]
+        $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt
+        $s9:tt $s10:tt $s11:tt $s12:tt $s13:tt $s14:tt $s15:tt $s16:tt
+        $s17:tt $s18:tt $s19:tt $s20:tt $s21:tt $s22:tt $s23:tt $s24:tt
+        $s25:tt $s26:tt $s27:tt $s28:tt $s29:tt $s30:tt $s31:tt $s32:tt
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(
+            @split $macro [] [] $($pending)*
+            $s1 $s2 $s3 $s4 $s5 $s6 $s7 $s8
+            $s9 $s10 $s11 $s12 $s13 $s14 $s15 $s16
+            $s17 $s18 $s19 $s20 $s21 $s22 $s23 $s24
+            $s25 $s26 $s27 $s28 $s29 $s30 $s31 $s32
+            $t1 $t2 $t3 $t4 $t5 $t6 ;
+        );
+        $crate::__batch!(@skip $macro [;] [] $($rest)*);
+    };
+
+    (
+        @skip $macro:ident $seen:tt [$($pending:tt)*]
+        $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt
+        $s9:tt $s10:tt $s11:tt $s12:tt $s13:tt $s14:tt $s15:tt $s16:tt
+        $s17:tt $s18:tt $s19:tt $s20:tt $s21:tt $s22:tt $s23:tt $s24:tt
+        $s25:tt $s26:tt $s27:tt $s28:tt $s29:tt $s30:tt $s31:tt $s32:tt
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(
+            @split $macro [] [] $($pending)*
+            $s1 $s2 $s3 $s4 $s5 $s6 $s7 $s8
+            $s9 $s10 $s11 $s12 $s13 $s14 $s15 $s16
+            $s17 $s18 $s19 $s20 $s21 $s22 $s23 $s24
+            $s25 $s26 $s27 $s28 $s29 $s30 $s31 $s32
+            $t1 $t2 $t3 $t4 $t5 $t6 $t7 ;
+        );
+        $crate::__batch!(@skip $macro [;] [] $($rest)*);
+    };
+
+    // no delimiter closely follows the skipped tokens, so only the first eight are set aside
+    (
+        @skip $macro:ident $seen:tt [$($pending:tt)*]
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $t8:tt $($rest:tt)*
+    ) => {
+        $crate::__batch!(
+            @skip $macro $seen [$($pending)* $t1 $t2 $t3 $t4 $t5 $t6 $t7 $t8] $($rest)*
+        );
+    };
+
+    // fewer than eight tokens are left
+    (@skip $macro:ident $seen:tt [$($pending:tt)*] $($rest:tt)*) => {
+        $crate::__batch!(@split $macro $seen [] $($pending)* $($rest)*);
+    };
+
+    (@split $macro:ident $seen:tt [$($entry:tt)*] ; $($rest:tt)*) => {
+        $crate::__batch!(@entry $macro [$($entry)*]);
+        $crate::__batch!(@split $macro [;] [] $($rest)*);
+    };
+
+    (@split $macro:ident $seen:tt [$($entry:tt)*] $t1:tt ; $($rest:tt)*) => {
+        $crate::__batch!(@entry $macro [$($entry)* $t1]);
+        $crate::__batch!(@split $macro [;] [] $($rest)*);
+    };
+
+    (@split $macro:ident $seen:tt [$($entry:tt)*] $t1:tt $t2:tt ; $($rest:tt)*) => {
+        $crate::__batch!(@entry $macro [$($entry)* $t1 $t2]);
+        $crate::__batch!(@split $macro [;] [] $($rest)*);
+    };
+
+    (@split $macro:ident $seen:tt [$($entry:tt)*] $t1:tt $t2:tt $t3:tt ; $($rest:tt)*) => {
+        $crate::__batch!(@entry $macro [$($entry)* $t1 $t2 $t3]);
+        $crate::__batch!(@split $macro [;] [] $($rest)*);
+    };
+
+    (
+        @split $macro:ident $seen:tt [$($entry:tt)*]
+        $t1:tt $t2:tt $t3:tt $t4:tt ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(@entry $macro [$($entry)* $t1 $t2 $t3 $t4]);
+        $crate::__batch!(@split $macro [;] [] $($rest)*);
+    };
+
+    (
+        @split $macro:ident $seen:tt [$($entry:tt)*]
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(@entry $macro [$($entry)* $t1 $t2 $t3 $t4 $t5]);
+        $crate::__batch!(@split $macro [;] [] $($rest)*);
+    };
+
+    (
+        @split $macro:ident $seen:tt [$($entry:tt)*]
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(@entry $macro [$($entry)* $t1 $t2 $t3 $t4 $t5 $t6]);
+        $crate::__batch!(@split $macro [;] [] $($rest)*);
+    };
+
+    (
+        @split $macro:ident $seen:tt [$($entry:tt)*]
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt ; $($rest:tt)*
+    ) => {
+        $crate::__batch!(@entry $macro [$($entry)* $t1 $t2 $t3 $t4 $t5 $t6 $t7]);
+        $crate::__batch!(@split $macro [;] [] $($rest)*);
+    };
+
+    // none of the next eight tokens are delimiters
+    (
+        @split $macro:ident $seen:tt [$($entry:tt)*]
+        $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $t8:tt $($rest:tt)*
+    ) => {
+        $crate::__batch!(
+            @split $macro $seen [$($entry)* $t1 $t2 $t3 $t4 $t5 $t6 $t7 $t8] $($rest)*
+        );
+    };
+
+    // final entry of a batch, without a trailing delimiter
+    (@split $macro:ident [;] [$($entry:tt)*] $($rest:tt)*) => {
+        $crate::__batch!(@entry $macro [$($entry)* $($rest)*]);
+    };
+
+    // not a batch, so the input was not valid for any of the macro's rules
+    (@split $macro:ident [] [$($entry:tt)*] $($rest:tt)*) => {
+        $crate::$macro!(@invalid $($entry)* $($rest)*);
+    };
+
+    (@entry $macro:ident []) => {};
+
+    (@entry $macro:ident [$($entry:tt)+]) => {
+        $crate::$macro!($($entry)+);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(dead_code, clippy::from_over_into)]
+
+    use alloc::string::{String, ToString as _};
+    use core::{fmt, ops::Deref};
+
+    struct UserId(String);
+    struct OrgId(String);
+    struct Seq(u64);
+    struct Wrapper<T>(T);
+
+    crate::impl_from! { String => UserId; String => OrgId; u64 => Seq; }
+    crate::impl_into!(UserId => String; OrgId => String; Seq => u64);
+    crate::impl_deref! {
+        #[automatically_derived]
+        UserId => String;
+        OrgId => 0: String;
+        <T> in Wrapper<T> => T
+    }
+    crate::forward_display! { UserId; OrgId; Seq; <T> in Wrapper<T> where T: fmt::Display }
+
+    static_assertions::assert_impl_all!(
+        UserId:
+        // impls
+        From<String>,
+        Into<String>,
+        Deref<Target = String>,
+        fmt::Display,
+    );
+    static_assertions::assert_impl_all!(OrgId: From<String>, Into<String>, Deref<Target = String>);
+    static_assertions::assert_impl_all!(Seq: From<u64>, Into<u64>, fmt::Display);
+    static_assertions::assert_impl_all!(Wrapper<u8>: Deref<Target = u8>, fmt::Display);
+
+    enum Level {
+        Debug,
+        Info,
+    }
+
+    enum Mode {
+        Fast,
+        Slow,
+    }
+
+    enum Color {
+        DarkRed,
+        LightBlue,
+    }
+
+    crate::impl_display_enum! {
+        Level: Debug => "debug", Info => "info";
+        Color: case = kebab, DarkRed, LightBlue;
+        Mode: Fast => "fast", Slow => "slow",
+    }
+
+    struct Code(u16);
+    struct Name(String);
+
+    crate::impls! {
+        impl_from! { u16 => Code; String => Name }
+        impl_as_ref! { Name => String }
+        forward_display! { Code; Name }
+        forward_from_str!(Code => u16; Name => String);
+    }
+
+    static_assertions::assert_impl_all!(Name: AsRef<String>, core::str::FromStr);
+
+    // more entries than the default recursion limit would allow when splitting one at a time
+    macro_rules! newtypes {
+        ($($name:ident)*) => {
+            mod plain {
+                $(pub struct $name(pub u8);)*
+
+                crate::impl_from! { $(u8 => $name;)* }
+            }
+
+            mod generic {
+                $(pub struct $name<T> { pub inner: T })*
+
+                crate::forward_display! {
+                    $(<T> in $name<T> where T: core::fmt::Display => inner;)*
+                }
+            }
+        };
+    }
+
+    newtypes!(
+        N0 N1 N2 N3 N4 N5 N6 N7 N8 N9 N10 N11 N12 N13 N14 N15 N16 N17 N18 N19 N20 N21 N22 N23 N24
+        N25 N26 N27 N28 N29 N30 N31 N32 N33 N34 N35 N36 N37 N38 N39 N40 N41 N42 N43 N44 N45 N46 N47
+        N48 N49 N50 N51 N52 N53 N54 N55 N56 N57 N58 N59 N60 N61 N62 N63 N64 N65 N66 N67 N68 N69 N70
+        N71 N72 N73 N74 N75 N76 N77 N78 N79 N80 N81 N82 N83 N84 N85 N86 N87 N88 N89 N90 N91 N92 N93
+        N94 N95 N96 N97 N98 N99 N100 N101 N102 N103 N104 N105 N106 N107 N108 N109 N110 N111 N112
+        N113 N114 N115 N116 N117 N118 N119 N120 N121 N122 N123 N124 N125 N126 N127 N128 N129
+    );
+
+    #[test]
+    fn batch() {
+        assert_eq!(UserId::from("alice".to_string()).len(), 5);
+        assert_eq!(Seq::from(3).to_string(), "3");
+        assert_eq!(Wrapper("w").to_string(), "w");
+        assert_eq!(Level::Info.to_string(), "info");
+        assert_eq!(Mode::Slow.to_string(), "slow");
+        assert_eq!(Color::LightBlue.to_string(), "light-blue");
+        assert_eq!(plain::N129::from(7).0, 7);
+        assert_eq!(generic::N129 { inner: 7 }.to_string(), "7");
+    }
+
+    #[test]
+    fn aggregate() {
+        let code = "404".parse::<Code>().unwrap();
+        assert_eq!(code.to_string(), "404");
+        assert_eq!(Name::from("x".to_string()).as_ref(), "x");
+    }
+}
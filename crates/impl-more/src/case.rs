@@ -0,0 +1,120 @@
+use core::fmt::{self, Write as _};
+
+/// Case style used to display an identifier.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    /// `lowercase`
+    Lower,
+
+    /// `snake_case`
+    Snake,
+
+    /// `kebab-case`
+    Kebab,
+
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+
+    /// `camelCase`
+    Camel,
+}
+
+/// Displays an identifier converted to a case style, without allocating.
+///
+/// Words are split at underscores and at lowercase-to-uppercase transitions. Runs of uppercase
+/// letters are kept together, so `HTTPError` is split into `HTTP` and `Error`.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct Cased<'a>(pub &'a str, pub Case);
+
+impl fmt::Display for Cased<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Cased(ident, case) = *self;
+
+        let separator = match case {
+            Case::Snake | Case::ScreamingSnake => Some('_'),
+            Case::Kebab => Some('-'),
+            Case::Lower | Case::Camel => None,
+        };
+
+        let mut chars = ident.chars().peekable();
+        let mut prev = None::<char>;
+        let mut words = 0;
+
+        while let Some(ch) = chars.next() {
+            if ch == '_' {
+                prev = None;
+                continue;
+            }
+
+            let starts_word = match prev {
+                None => true,
+                Some(prev) => {
+                    let next_is_lower = chars.peek().map_or(false, |next| next.is_lowercase());
+                    ch.is_uppercase() && (!prev.is_uppercase() || next_is_lower)
+                }
+            };
+
+            if starts_word {
+                if let (Some(sep), true) = (separator, words > 0) {
+                    fmt.write_char(sep)?;
+                }
+
+                words += 1;
+            }
+
+            let upper = match case {
+                Case::ScreamingSnake => true,
+                Case::Camel => starts_word && words > 1,
+                Case::Lower | Case::Snake | Case::Kebab => false,
+            };
+
+            if upper {
+                for ch in ch.to_uppercase() {
+                    fmt.write_char(ch)?;
+                }
+            } else {
+                for ch in ch.to_lowercase() {
+                    fmt.write_char(ch)?;
+                }
+            }
+
+            prev = Some(ch);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString as _;
+
+    use super::*;
+
+    #[test]
+    fn word_boundaries() {
+        assert_eq!(Cased("Debug", Case::Snake).to_string(), "debug");
+        assert_eq!(Cased("NotFound", Case::Snake).to_string(), "not_found");
+        assert_eq!(Cased("HTTPError", Case::Snake).to_string(), "http_error");
+        assert_eq!(Cased("Ipv4Addr", Case::Snake).to_string(), "ipv4_addr");
+        assert_eq!(Cased("Status404", Case::Snake).to_string(), "status404");
+        assert_eq!(Cased("Already_Split", Case::Snake).to_string(), "already_split");
+        assert_eq!(Cased("IO", Case::Snake).to_string(), "io");
+    }
+
+    #[test]
+    fn case_styles() {
+        let ident = "NotFoundHTTPError";
+
+        assert_eq!(Cased(ident, Case::Lower).to_string(), "notfoundhttperror");
+        assert_eq!(Cased(ident, Case::Snake).to_string(), "not_found_http_error");
+        assert_eq!(Cased(ident, Case::Kebab).to_string(), "not-found-http-error");
+        assert_eq!(
+            Cased(ident, Case::ScreamingSnake).to_string(),
+            "NOT_FOUND_HTTP_ERROR"
+        );
+        assert_eq!(Cased(ident, Case::Camel).to_string(), "notFoundHttpError");
+    }
+}
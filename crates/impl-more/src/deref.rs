@@ -2,7 +2,8 @@
 ///
 /// The first argument is that of the newtype struct to create the impl for and the second is the
 /// deref target type. The third argument is required for non-newtype structs and is the name of the
-/// field to deref to. Type parameters require special handling, see examples.
+/// field to deref to. Tuple indexes and dotted field paths can also be used to select nested or
+/// non-first fields. Type parameters require special handling, see examples.
 ///
 /// Also see [`impl_deref_mut`], [`impl_deref_and_mut`], and [`forward_deref_and_mut`].
 ///
@@ -25,50 +26,84 @@
 /// assert_eq!(foo.len(), 3);
 /// ```
 ///
+/// With a newtype struct over borrowed data:
+/// ```
+/// struct Name<'a>(&'a str);
+/// impl_more::impl_deref!(<'a> in Name<'a> => str);
+///
+/// let name = Name("two");
+/// assert_eq!(name.len(), 3);
+/// ```
+///
+/// With a field path:
+/// ```
+/// use std::marker::PhantomData;
+///
+/// struct Buffer { bytes: Vec<u8> }
+/// struct Tagged<M>(PhantomData<M>, Buffer);
+/// impl_more::impl_deref!(<M> in Tagged<M> => 1.bytes: Vec<u8>);
+///
+/// let tagged = Tagged::<()>(PhantomData, Buffer { bytes: vec![1, 2, 3] });
+/// assert_eq!(tagged.len(), 3);
+/// ```
+///
 /// [`Deref`]: core::ops::Deref
 /// [`impl_deref_mut`]: crate::impl_deref_mut
 /// [`impl_deref_and_mut`]: crate::impl_deref_and_mut
 /// [`forward_deref_and_mut`]: crate::forward_deref_and_mut
 #[macro_export]
 macro_rules! impl_deref {
-    (<$($generic:ident),+> in $this:ty => $target:ty) => {
-        impl <$($generic),+> ::core::ops::Deref for $this {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_deref [$(#[$attr])*] [=>] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $this:ty => $($rest:tt)*) => {
+        $crate::impl_deref!(@impl [$(#[$attr])*] [] [$this] [] => $($rest)*);
+    };
+
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*] => $target:ty) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::ops::Deref for $this where $($where)* {
             type Target = $target;
 
+            #[inline]
             fn deref(&self) -> &Self::Target {
                 &self.0
             }
         }
     };
 
-    (<$($generic:ident),+> in $this:ty => $field:ident : $target:ty) => {
-        impl <$($generic),+> ::core::ops::Deref for $this {
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*]
+        => $($field:tt).+ : $target:ty
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::ops::Deref for $this where $($where)* {
             type Target = $target;
 
+            #[inline]
             fn deref(&self) -> &Self::Target {
-                &self.$field
+                &self.$($field).+
             }
         }
     };
 
-    ($this:ty => $target:ty) => {
-        impl ::core::ops::Deref for $this {
-            type Target = $target;
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!("missing target type; expected `impl_deref!(Type => Target)`");
+    };
 
-            fn deref(&self) -> &Self::Target {
-                &self.0
-            }
-        }
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!("missing target type; expected `impl_deref!(Type => Target)`");
     };
 
-    ($this:ty => $field:ident : $target:ty) => {
-        impl ::core::ops::Deref for $this {
-            type Target = $target;
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(
+            "expected `impl_deref!(Type => Target)` or `impl_deref!(Type => field: Target)`"
+        );
+    };
 
-            fn deref(&self) -> &Self::Target {
-                &self.$field
-            }
-        }
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_deref $($input)*);
     };
 }
 
@@ -117,37 +152,47 @@ macro_rules! impl_deref {
 /// [`forward_deref_and_mut`]: crate::forward_deref_and_mut
 #[macro_export]
 macro_rules! impl_deref_mut {
-    (<$($generic:ident),+> in $this:ty) => {
-        impl <$($generic),+> ::core::ops::DerefMut for $this {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.0
-            }
-        }
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_deref_mut [$(#[$attr])*] [=>] <$($rest)*);
     };
 
-    (<$($generic:ident),+> in $this:ty => $field:ident) => {
-        impl <$($generic),+> ::core::ops::DerefMut for $this {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.$field
-            }
-        }
+    ($(#[$attr:meta])* $this:ty) => {
+        $crate::impl_deref_mut!(@impl [$(#[$attr])*] [] [$this] []);
+    };
+
+    ($(#[$attr:meta])* $this:ty => $($rest:tt)*) => {
+        $crate::impl_deref_mut!(@impl [$(#[$attr])*] [] [$this] [] => $($rest)*);
     };
 
-    ($this:ty) => {
-        impl ::core::ops::DerefMut for $this {
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*]) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::ops::DerefMut for $this where $($where)* {
+            #[inline]
             fn deref_mut(&mut self) -> &mut Self::Target {
                 &mut self.0
             }
         }
     };
 
-    ($this:ty => $field:ident) => {
-        impl ::core::ops::DerefMut for $this {
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*] => $($field:tt).+) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::ops::DerefMut for $this where $($where)* {
+            #[inline]
             fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.$field
+                &mut self.$($field).+
             }
         }
     };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(
+            "expected `impl_deref_mut!(Type)` or `impl_deref_mut!(Type => field)`"
+        );
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_deref_mut $($input)*);
+    };
 }
 
 /// Implements [`Deref`] and [`DerefMut`] by forwarding through an inner field's implementation.
@@ -182,68 +227,45 @@ macro_rules! impl_deref_mut {
 /// [`forward_deref_and_mut`]: crate::forward_deref_and_mut
 #[macro_export]
 macro_rules! impl_deref_and_mut {
-    (<$($generic:ident),+> in $this:ty => $target:ty) => {
-        impl <$($generic),+> ::core::ops::Deref for $this {
-            type Target = $target;
-
-            fn deref(&self) -> &Self::Target {
-                &self.0
-            }
-        }
-
-        impl <$($generic),+> ::core::ops::DerefMut for $this {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.0
-            }
-        }
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_deref_and_mut [$(#[$attr])*] [=>] <$($rest)*);
     };
 
-    (<$($generic:ident),+> in $this:ty => $field:ident : $target:ty) => {
-        impl <$($generic),+> ::core::ops::Deref for $this {
-            type Target = $target;
-
-            fn deref(&self) -> &Self::Target {
-                &self.$field
-            }
-        }
-
-        impl <$($generic),+> ::core::ops::DerefMut for $this {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.$field
-            }
-        }
+    ($(#[$attr:meta])* $this:ty => $($rest:tt)*) => {
+        $crate::impl_deref_and_mut!(@impl [$(#[$attr])*] [] [$this] [] => $($rest)*);
     };
 
-    ($this:ty => $target:ty) => {
-        impl ::core::ops::Deref for $this {
-            type Target = $target;
+    (@impl $ctx:tt $generics:tt $this:tt $where:tt => $target:ty) => {
+        $crate::impl_deref!(@impl $ctx $generics $this $where => $target);
+        $crate::impl_deref_mut!(@impl $ctx $generics $this $where);
+    };
 
-            fn deref(&self) -> &Self::Target {
-                &self.0
-            }
-        }
+    (@impl $ctx:tt $generics:tt $this:tt $where:tt => $($field:tt).+ : $target:ty) => {
+        $crate::impl_deref!(@impl $ctx $generics $this $where => $($field).+ : $target);
+        $crate::impl_deref_mut!(@impl $ctx $generics $this $where => $($field).+);
+    };
 
-        impl ::core::ops::DerefMut for $this {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.0
-            }
-        }
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!(
+            "missing target type; expected `impl_deref_and_mut!(Type => Target)`"
+        );
     };
 
-    ($this:ty => $field:ident : $target:ty) => {
-        impl ::core::ops::Deref for $this {
-            type Target = $target;
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!(
+            "missing target type; expected `impl_deref_and_mut!(Type => Target)`"
+        );
+    };
 
-            fn deref(&self) -> &Self::Target {
-                &self.$field
-            }
-        }
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `impl_deref_and_mut!(Type => Target)` or ",
+            "`impl_deref_and_mut!(Type => field: Target)`",
+        ));
+    };
 
-        impl ::core::ops::DerefMut for $this {
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.$field
-            }
-        }
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_deref_and_mut $($input)*);
     };
 }
 
@@ -273,69 +295,123 @@ macro_rules! impl_deref_and_mut {
 /// [`DerefMut`]: core::ops::DerefMut
 #[macro_export]
 macro_rules! forward_deref_and_mut {
-    ($ty:ty => $target:ty) => {
-        impl ::core::ops::Deref for $ty {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(forward_deref_and_mut [$(#[$attr])*] [=>] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ty => $($rest:tt)*) => {
+        $crate::forward_deref_and_mut!(@impl [$(#[$attr])*] [] [$ty] [] => $($rest)*);
+    };
+
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*] => $target:ty) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::ops::Deref for $ty where $($where)* {
             type Target = $target;
 
+            #[inline]
             fn deref(&self) -> &Self::Target {
                 ::core::ops::Deref::deref(&self.0)
             }
         }
 
-        impl ::core::ops::DerefMut for $ty {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::ops::DerefMut for $ty where $($where)* {
+            #[inline]
             fn deref_mut(&mut self) -> &mut Self::Target {
                 ::core::ops::DerefMut::deref_mut(&mut self.0)
             }
         }
     };
 
-    ($ty:ty => ref $target:ty) => {
-        impl<'__impl_more_a> ::core::ops::Deref for $ty {
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*] => ref $target:ty) => {
+        $(#[$attr])*
+        impl <'__impl_more_a, $($generics)*> ::core::ops::Deref for $ty where $($where)* {
             type Target = $target;
 
+            #[inline]
             fn deref(&self) -> &Self::Target {
                 ::core::ops::Deref::deref(&self.0)
             }
         }
 
-        impl<'__impl_more_a> ::core::ops::DerefMut for $ty {
+        $(#[$attr])*
+        impl <'__impl_more_a, $($generics)*> ::core::ops::DerefMut for $ty where $($where)* {
+            #[inline]
             fn deref_mut(&mut self) -> &mut Self::Target {
                 ::core::ops::DerefMut::deref_mut(&mut self.0)
             }
         }
     };
 
-    ($ty:ty => $field:ident : $target:ty) => {
-        impl ::core::ops::Deref for $ty {
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]
+        => $($field:tt).+ : $target:ty
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::ops::Deref for $ty where $($where)* {
             type Target = $target;
 
+            #[inline]
             fn deref(&self) -> &Self::Target {
-                ::core::ops::Deref::deref(&self.$field)
+                ::core::ops::Deref::deref(&self.$($field).+)
             }
         }
 
-        impl ::core::ops::DerefMut for $ty {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::ops::DerefMut for $ty where $($where)* {
+            #[inline]
             fn deref_mut(&mut self) -> &mut Self::Target {
-                ::core::ops::DerefMut::deref_mut(&mut self.$field)
+                ::core::ops::DerefMut::deref_mut(&mut self.$($field).+)
             }
         }
     };
 
-    ($ty:ty => $field:ident : ref $target:ty) => {
-        impl<'__impl_more_a> ::core::ops::Deref for $ty {
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]
+        => $($field:tt).+ : ref $target:ty
+    ) => {
+        $(#[$attr])*
+        impl <'__impl_more_a, $($generics)*> ::core::ops::Deref for $ty where $($where)* {
             type Target = $target;
 
+            #[inline]
             fn deref(&self) -> &Self::Target {
-                ::core::ops::Deref::deref(&self.$field)
+                ::core::ops::Deref::deref(&self.$($field).+)
             }
         }
 
-        impl<'__impl_more_a> ::core::ops::DerefMut for $ty {
+        $(#[$attr])*
+        impl <'__impl_more_a, $($generics)*> ::core::ops::DerefMut for $ty where $($where)* {
+            #[inline]
             fn deref_mut(&mut self) -> &mut Self::Target {
-                ::core::ops::DerefMut::deref_mut(&mut self.$field)
+                ::core::ops::DerefMut::deref_mut(&mut self.$($field).+)
             }
         }
     };
+
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!(
+            "missing target type; expected `forward_deref_and_mut!(Type => Target)`"
+        );
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!(
+            "missing target type; expected `forward_deref_and_mut!(Type => Target)`"
+        );
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `forward_deref_and_mut!(Type => Target)`, ",
+            "`forward_deref_and_mut!(Type => ref Target)`, ",
+            "or `forward_deref_and_mut!(Type => field: Target)`",
+        ));
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(forward_deref_and_mut $($input)*);
+    };
 }
 
 #[cfg(test)]
@@ -410,6 +486,135 @@ mod tests {
         DerefMut<Target = usize>,
     );
 
+    struct Bounded<T>(T);
+    impl_deref!(<T: Clone> in Bounded<T> => T);
+    impl_deref_mut!(<T> in Bounded<T> where T: Clone);
+    static_assertions::assert_impl_all!(
+        Bounded<usize>:
+        // impls
+        Deref<Target = usize>,
+        DerefMut<Target = usize>,
+    );
+    static_assertions::assert_not_impl_any!(
+        Bounded<Foo1>:
+        // impls
+        Deref,
+        DerefMut,
+    );
+
+    struct Name<'a>(&'a str);
+    impl_deref!(<'a> in Name<'a> => str);
+    static_assertions::assert_impl_all!(
+        Name<'static>:
+        // impls
+        Deref<Target = str>,
+    );
+
+    struct ViewMut<'a, T> {
+        items: &'a mut [T],
+    }
+    forward_deref_and_mut!(<'a, T> in ViewMut<'a, T> => items: [T]);
+    static_assertions::assert_impl_all!(
+        ViewMut<'static, usize>:
+        // impls
+        Deref<Target = [usize]>,
+        DerefMut<Target = [usize]>,
+    );
+
+    struct Frame<const N: usize>([u8; N]);
+    impl_deref!(<const N: usize> in Frame<N> => [u8; N]);
+    impl_deref_mut!(<const N: usize> in Frame<N>);
+    static_assertions::assert_impl_all!(
+        Frame<4>:
+        // impls
+        Deref<Target = [u8; 4]>,
+        DerefMut<Target = [u8; 4]>,
+    );
+
+    struct Buffer<T, const N: usize> {
+        items: [T; N],
+    }
+    impl_deref_and_mut!(<T, const N: usize> in Buffer<T, N> => items: [T; N]);
+    static_assertions::assert_impl_all!(
+        Buffer<usize, 4>:
+        // impls
+        Deref<Target = [usize; 4]>,
+        DerefMut<Target = [usize; 4]>,
+    );
+
+    struct Tagged<M>(core::marker::PhantomData<M>, String);
+    impl_deref!(<M> in Tagged<M> => 1: String);
+    impl_deref_mut!(<M> in Tagged<M> => 1);
+    static_assertions::assert_impl_all!(
+        Tagged<()>:
+        // impls
+        Deref<Target = String>,
+        DerefMut<Target = String>,
+    );
+
+    struct Pair((u8, String));
+    impl_deref_and_mut!(Pair => 0.1: String);
+    static_assertions::assert_impl_all!(
+        Pair:
+        // impls
+        Deref<Target = String>,
+        DerefMut<Target = String>,
+    );
+
+    struct Inner {
+        msg: String,
+    }
+    struct Outer {
+        inner: Inner,
+    }
+    forward_deref_and_mut!(Outer => inner.msg: ref str);
+    static_assertions::assert_impl_all!(
+        Outer:
+        // impls
+        Deref<Target = str>,
+        DerefMut<Target = str>,
+    );
+
+    #[test]
+    fn field_path_impls() {
+        let mut tagged = Tagged::<()>(core::marker::PhantomData, "foo".to_owned());
+        tagged.push('!');
+        assert_eq!(*tagged, "foo!");
+
+        let mut pair = Pair((1, "bar".to_owned()));
+        pair.push('!');
+        assert_eq!(*pair, "bar!");
+
+        let mut outer = Outer {
+            inner: Inner {
+                msg: "baz".to_owned(),
+            },
+        };
+        accepts_mut_string_slice(&mut outer);
+        assert_eq!(&*outer, "baz");
+    }
+
+    #[test]
+    fn const_generic_impls() {
+        let mut frame = Frame([1, 2, 3]);
+        frame.reverse();
+        assert_eq!(*frame, [3, 2, 1]);
+
+        let buf = Buffer { items: [1, 2, 3] };
+        assert_eq!(buf.len(), 3);
+    }
+
+    #[test]
+    fn borrowed_impls() {
+        let name = Name("foo");
+        accepts_string_slice(&name);
+
+        let mut items = [1, 2, 3];
+        let mut view = ViewMut { items: &mut items };
+        view.reverse();
+        assert_eq!(*view, [3, 2, 1]);
+    }
+
     #[test]
     fn foo2_impls() {
         let mut foo = Foo2("".to_owned());
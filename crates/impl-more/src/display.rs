@@ -1,6 +1,6 @@
 /// Implements [`Display`] for structs by forwarding to one of its field.
 ///
-/// Emitted code is not compatible with `#[no_std]`.
+/// Emitted code is compatible with `#[no_std]` and does not allocate.
 ///
 /// Newtype structs can omit the field identifier.
 ///
@@ -41,39 +41,34 @@
 /// assert_eq!(Baz(42u64).to_string(), "42");
 /// ```
 ///
+/// To only bound the forwarded field's type, so that marker type parameters remain unconstrained,
+/// specify the field's type. Alternatively, declare bounds or a `where` clause in the generics
+/// header, in which case no bounds are added.
+///
+/// ```
+/// # use impl_more::forward_display;
+/// use std::marker::PhantomData;
+///
+/// struct Unit;
+///
+/// struct Tagged<T, M>(T, PhantomData<M>);
+/// impl_more::forward_display!(<T, M> in Tagged<T, M> => 0: T);
+///
+/// struct Labelled<T, M> {
+///     label: T,
+///     marker: PhantomData<M>,
+/// }
+/// impl_more::forward_display!(<T, M> in Labelled<T, M> where T: std::fmt::Display => label);
+///
+/// assert_eq!(Tagged(42, PhantomData::<Unit>).to_string(), "42");
+/// assert_eq!(Labelled { label: "hi", marker: PhantomData::<Unit> }.to_string(), "hi");
+/// ```
+///
 /// [`Display`]: std::fmt::Display
 #[macro_export]
 macro_rules! forward_display {
-    (<$($generic:ident),+> in $this:ty => $field:ident) => {
-        impl <$($generic: ::core::fmt::Display),+> ::core::fmt::Display for $this {
-            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                ::core::fmt::Display::fmt(&self.$field, fmt)
-            }
-        }
-    };
-
-    (<$($generic:ident),+> in $this:ty) => {
-        impl <$($generic: ::core::fmt::Display),+> ::core::fmt::Display for $this {
-            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                ::core::fmt::Display::fmt(&self.0, fmt)
-            }
-        }
-    };
-
-    ($ty:ty) => {
-        impl ::core::fmt::Display for $ty {
-            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                ::core::fmt::Display::fmt(&self.0, fmt)
-            }
-        }
-    };
-
-    ($ty:ty => $field:ident) => {
-        impl ::core::fmt::Display for $ty {
-            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                ::core::fmt::Display::fmt(&self.$field, fmt)
-            }
-        }
+    ($($input:tt)*) => {
+        $crate::__forward_fmt!(forward_display Display $($input)*);
     };
 }
 
@@ -105,39 +100,183 @@ macro_rules! forward_display {
 /// assert_eq!(Hello3.to_string(), "hello world");
 /// ```
 ///
+/// For generic types, the generics header is used verbatim, so any bounds required by the format
+/// args must be declared.
+///
+/// ```
+/// use std::marker::PhantomData;
+///
+/// struct Marker<T>(PhantomData<T>);
+/// impl_more::impl_display!(<T> in Marker<T>: "marker");
+///
+/// struct Defaulted<T>(PhantomData<T>);
+/// impl_more::impl_display!(
+///     <T> in Defaulted<T> where T: Default + std::fmt::Display:
+///     "default: {}", T::default()
+/// );
+///
+/// assert_eq!(Marker::<String>(PhantomData).to_string(), "marker");
+/// assert_eq!(Defaulted::<u8>(PhantomData).to_string(), "default: 0");
+/// ```
+///
+/// Fields can be destructured from `self` by following the type with a pattern. Generic types with
+/// a pattern should declare bounds in the generics header instead of a `where` clause.
+///
+/// ```
+/// struct Span {
+///     start: u32,
+///     end: u32,
+/// }
+///
+/// impl_more::impl_display!(Span { start, end }: "{start}..{end}");
+/// assert_eq!(Span { start: 2, end: 4 }.to_string(), "2..4");
+///
+/// struct Pair<T>(T, T);
+///
+/// impl_more::impl_display!(<T: std::fmt::Display> in Pair<T>(a, b): "({a}, {b})");
+/// assert_eq!(Pair(1, 2).to_string(), "(1, 2)");
+/// ```
+///
+/// An alternate format string, used when formatting with `{:#}`, can follow a `|`. Alternate
+/// format strings only support inline format args.
+///
+/// ```
+/// struct Version;
+/// impl_more::impl_display!(Version: "v1" | "version 1.0.0");
+/// assert_eq!(format!("{}", Version), "v1");
+/// assert_eq!(format!("{:#}", Version), "version 1.0.0");
+/// ```
+///
+/// Width, fill, alignment, and precision flags are applied to the formatted output.
+///
+/// ```
+/// struct Status;
+/// impl_more::impl_display!(Status: "{}/{}", 3, 4);
+/// assert_eq!(format!("[{:>6}]", Status), "[   3/4]");
+/// assert_eq!(format!("[{:.2}]", Status), "[3/]");
+/// ```
+///
 /// [`Display`]: std::fmt::Display
 #[macro_export]
 macro_rules! impl_display {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_display [$(#[$attr])*] [:] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ident { $($fields:tt)* }: $($rest:tt)*) => {
+        $crate::impl_display!(@impl [$(#[$attr])*] [] [$ty] [] { $($fields)* } : $($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ident ($($fields:tt)*): $($rest:tt)*) => {
+        $crate::impl_display!(@impl [$(#[$attr])*] [] [$ty] [] ($($fields)*) : $($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ty: $($rest:tt)*) => {
+        $crate::impl_display!(@impl [$(#[$attr])*] [] [$ty] [] : $($rest)*);
+    };
+
     // no format args
-    ($ty:ty: $format:literal) => {
-        impl ::core::fmt::Display for $ty {
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]
+        [$($pattern:tt)?] : $format:literal
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::fmt::Display for $ty where $($where)* {
             fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                ::core::write!(fmt, $format)
+                $(let Self $pattern = self;)?
+                $crate::__pad_args(fmt, ::core::format_args!($format))
+            }
+        }
+    };
+
+    // with alternate format string
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]
+        [$($pattern:tt)?] : $format:literal | $alt:literal
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::fmt::Display for $ty where $($where)* {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $(let Self $pattern = self;)?
+
+                if fmt.alternate() {
+                    $crate::__pad_args(fmt, ::core::format_args!($alt))
+                } else {
+                    $crate::__pad_args(fmt, ::core::format_args!($format))
+                }
             }
         }
     };
 
     // with explicit format args
-    ($ty:ty: $format:literal, $($args:expr),+) => {
-        impl ::core::fmt::Display for $ty {
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]
+        [$($pattern:tt)?] : $format:literal, $($args:expr),+
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::fmt::Display for $ty where $($where)* {
             fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                ::core::write!(fmt, $format, $($args),+)
+                $(let Self $pattern = self;)?
+                $crate::__pad_args(fmt, ::core::format_args!($format, $($args),+))
             }
         }
     };
 
     // strip trailing comma and forward to format args branch
-    ($ty:ty: $format:literal, $($args:expr),+ ,) => {
-        $crate::impl_display!($ty: $format, $($args),+);
+    (
+        @impl $ctx:tt $generics:tt $ty:tt $where:tt [$($pattern:tt)?]
+        : $format:literal, $($args:expr),+ ,
+    ) => {
+        $crate::impl_display!(@impl $ctx $generics $ty $where [$($pattern)?] : $format, $($args),+);
+    };
+
+    // destructure fields of self
+    (@impl $ctx:tt $generics:tt $ty:tt $where:tt { $($fields:tt)* } : $($rest:tt)*) => {
+        $crate::impl_display!(@impl $ctx $generics $ty $where [{ $($fields)* }] : $($rest)*);
+    };
+
+    (@impl $ctx:tt $generics:tt $ty:tt $where:tt ($($fields:tt)*) : $($rest:tt)*) => {
+        $crate::impl_display!(@impl $ctx $generics $ty $where [($($fields)*)] : $($rest)*);
+    };
+
+    (@impl $ctx:tt $generics:tt $ty:tt $where:tt : $($rest:tt)*) => {
+        $crate::impl_display!(@impl $ctx $generics $ty $where [] : $($rest)*);
+    };
+
+    (@invalid $(#[$attr:meta])* $this:ty, $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `:` after the type, as in `impl_display!(Type: \"format\")`; ",
+            "the `,` delimiter was replaced in v0.2.0",
+        ));
+    };
+
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!("missing format string; expected `impl_display!(Type: \"format\")`");
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!("missing format string; expected `impl_display!(Type: \"format\")`");
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `impl_display!(Type: \"format\")` or ",
+            "`impl_display!(Type: \"format\", args...)`",
+        ));
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_display $($input)*);
     };
 }
 
 /// Implements [`Display`] for enums using a static string or format args for each variant.
 ///
+/// Emitted code is compatible with `#[no_std]` and does not allocate.
+///
 /// # Examples
 ///
 /// ```
-/// # extern crate alloc;
 /// use impl_more::impl_display_enum;
 ///
 /// enum Foo {
@@ -161,97 +300,334 @@ macro_rules! impl_display {
 /// assert_eq!(CoordOrMsg::Msg("hi").to_string(), "message: hi");
 /// ```
 ///
+/// Unit, tuple, and struct variants can be mixed.
+///
+/// ```
+/// use impl_more::impl_display_enum;
+///
+/// enum Event {
+///     Tick,
+///     Key(char),
+///     Move { x: i32, y: i32 },
+/// }
+///
+/// impl_display_enum!(Event: Tick => "tick", Key(key) => "key {key}", Move { x, y } => "{x}, {y}");
+///
+/// assert_eq!(Event::Tick.to_string(), "tick");
+/// assert_eq!(Event::Move { x: 1, y: 2 }.to_string(), "1, 2");
+/// ```
+///
+/// Each variant can have an alternate format string, used when formatting with `{:#}`, following a
+/// `|`.
+///
+/// ```
+/// use impl_more::impl_display_enum;
+///
+/// enum ApiError {
+///     NotFound,
+///     RateLimited { secs: u64 },
+/// }
+///
+/// impl_display_enum!(
+///     ApiError:
+///     NotFound => "not found" | "resource was not found on the server",
+///     RateLimited { secs } => "rate limited" | "rate limited; retry after {secs} seconds",
+/// );
+///
+/// assert_eq!(format!("{}", ApiError::NotFound), "not found");
+/// assert_eq!(
+///     format!("{:#}", ApiError::RateLimited { secs: 5 }),
+///     "rate limited; retry after 5 seconds",
+/// );
+/// ```
+///
+/// Prefixing the variants with `case = style;` displays variants listed without a format string
+/// using their name, converted to one of the `lower`, `snake`, `kebab`, `screaming_snake`, or
+/// `camel` case styles. Fields of tuple and struct variants can be ignored with `(..)` and
+/// `{ .. }`, respectively, and any variant can still be given its own format string. The prefix
+/// can also end in a comma, which is required when batching several enums in one invocation.
+///
+/// ```
+/// use impl_more::impl_display_enum;
+///
+/// enum Level {
+///     Debug,
+///     NotFound,
+///     Custom(u8),
+///     Other { code: u16 },
+/// }
+///
+/// impl_display_enum!(
+///     Level: case = kebab;
+///     Debug,
+///     NotFound,
+///     Custom(..),
+///     Other { code } => "other {code}",
+/// );
+///
+/// assert_eq!(Level::Debug.to_string(), "debug");
+/// assert_eq!(Level::NotFound.to_string(), "not-found");
+/// assert_eq!(Level::Custom(1).to_string(), "custom");
+/// assert_eq!(Level::Other { code: 2 }.to_string(), "other 2");
+/// ```
+///
+/// For generic enums, the generics header is used verbatim, so only the parameters displayed by a
+/// format string need a `Display` bound:
+///
+/// ```
+/// use std::{fmt, marker::PhantomData};
+///
+/// use impl_more::impl_display_enum;
+///
+/// enum Event<T, M> {
+///     Msg(T),
+///     Tick(PhantomData<M>),
+/// }
+///
+/// impl_display_enum!(
+///     <T: fmt::Display, M> in Event<T, M>:
+///     Msg(msg) => "message: {msg}",
+///     Tick(_) => "tick",
+/// );
+///
+/// struct NotDisplay;
+///
+/// assert_eq!(Event::<_, NotDisplay>::Msg("hi").to_string(), "message: hi");
+/// assert_eq!(Event::<u8, NotDisplay>::Tick(PhantomData).to_string(), "tick");
+/// ```
+///
+/// Width, fill, alignment, and precision flags are applied to each variant's output, which is
+/// useful for aligning values in tables.
+///
+/// ```
+/// use impl_more::impl_display_enum;
+///
+/// enum State {
+///     Idle,
+///     Busy(u8),
+/// }
+///
+/// impl_display_enum!(State: Idle => "idle", Busy(n) => "busy ({n})");
+///
+/// assert_eq!(format!("[{:<8}]", State::Idle), "[idle    ]");
+/// assert_eq!(format!("[{:*^10}]", State::Busy(2)), "[*busy (2)*]");
+/// ```
+///
 /// [`Display`]: std::fmt::Display
 #[macro_export]
 macro_rules! impl_display_enum {
-    ($ty:ty: $($variant:ident => $stringified:literal),+) => {
-        impl ::core::fmt::Display for $ty {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_display_enum [$(#[$attr])*] [:] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ty: $($rest:tt)*) => {
+        $crate::impl_display_enum!(@impl [$(#[$attr])*] [] [$ty] [] : $($rest)*);
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*] :
+        $($variant:ident => $stringified:literal $(| $alt:literal)?),+
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::fmt::Display for $ty where $($where)* {
             fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                fmt.write_str(match self {
+                fmt.pad(match self {
                     $(
-                        Self::$variant => $stringified,
+                        Self::$variant => $(if fmt.alternate() { $alt } else)? { $stringified },
                     )*
                 })
             }
         }
     };
 
-    ($ty:ty: $($variant:ident => $stringified:literal),+ ,) => {
-        $crate::impl_display_enum!($ty: $($variant => $stringified),+);
+    (
+        @impl $ctx:tt $generics:tt $ty:tt $where:tt :
+        $($variant:ident => $stringified:literal $(| $alt:literal)?),+ ,
+    ) => {
+        $crate::impl_display_enum!(
+            @impl $ctx $generics $ty $where : $($variant => $stringified $(| $alt)?),+
+        );
     };
 
-    ($ty:ty: $($variant:ident ($($inner:tt),+) => $format:literal),+) => {
-        impl ::core::fmt::Display for $ty {
-            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                use ::core::fmt::Write as _;
+    (
+        @impl $ctx:tt $generics:tt $ty:tt $where:tt :
+        case = $case:ident , $($variants:tt)+
+    ) => {
+        $crate::impl_display_enum!(@arms $ctx $generics $ty $where [$case] $($variants)+);
+    };
 
-                // a more efficient method (format_args) is blocked by:
-                // https://github.com/rust-lang/rust/issues/15023
-                let mut buf = ::std::string::String::new();
+    (
+        @impl $ctx:tt $generics:tt $ty:tt $where:tt :
+        case = $case:ident ; $($variants:tt)+
+    ) => {
+        $crate::impl_display_enum!(@arms $ctx $generics $ty $where [$case] $($variants)+);
+    };
 
+    (@impl $ctx:tt $generics:tt $ty:tt $where:tt : $($variants:tt)+) => {
+        $crate::impl_display_enum!(@arms $ctx $generics $ty $where [] $($variants)+);
+    };
+
+    // variants of any shape, with format strings unless displayed using their case-converted name
+    (
+        @arms [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*] $case:tt
+        $(
+            $variant:ident $(($($inner:tt),+))? $({ $($fields:tt)* })?
+            $(=> $format:literal $(| $alt:literal)?)?
+        ),+ $(,)?
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::fmt::Display for $ty where $($where)* {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 match self {
                     $(
-                        Self::$variant($($crate::impl_display_enum!(iou @ $inner)),+) =>
-                            ::core::write!(&mut buf, $format)?,
-                    )*
-                };
-
-                fmt.write_str(&buf)
+                        Self::$variant $(($($inner),+))? $({ $($fields)* })? => {
+                            $crate::impl_display_enum!(
+                                @write fmt $case $variant $(=> $format $(| $alt)?)?
+                            )
+                        }
+                    )+
+                }
             }
         }
     };
 
-    ($ty:ty: $($variant:ident ($($inner:tt),+) => $format:literal),+ ,) => {
-        $crate::impl_display_enum!($ty: $($variant ($($inner),+) => $format),+);
+    // finds the first invalid variant, only used to report errors
+    (
+        @find_invalid
+        $variant:ident $(($($inner:tt),+))? $({ $($fields:tt)* })?
+        $(=> $format:literal $(| $alt:literal)?)?
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_display_enum!(@find_invalid $($($rest)*)?);
     };
 
-    ($ty:ty: $($variant:ident { $($inner:ident),+ } => $format:literal),+) => {
-        impl ::core::fmt::Display for $ty {
-            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                use ::core::fmt::Write as _;
+    (@find_invalid $next:tt $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "invalid variant `",
+            ::core::stringify!($next),
+            "`; expected variants of the form `Variant => \"format\"`, ",
+            "`Variant(a, b) => \"format\"`, or `Variant { a, b } => \"format\"`",
+        ));
+    };
 
-                // a more efficient method (format_args) is blocked by:
-                // https://github.com/rust-lang/rust/issues/15023
-                let mut buf = ::std::string::String::new();
+    (@find_invalid) => {
+        ::core::compile_error!("expected `impl_display_enum!(Type: Variant => \"format\", ...)`");
+    };
 
-                match self {
-                    $(
-                        Self::$variant { $($inner),+ } =>
-                            ::core::write!(&mut buf, $format)?,
-                    )*
-                };
+    (@write $fmt:ident $case:tt $variant:ident => $format:literal) => {
+        $crate::__pad_args($fmt, ::core::format_args!($format))
+    };
 
-                fmt.write_str(&buf)
-            }
+    (@write $fmt:ident $case:tt $variant:ident => $format:literal | $alt:literal) => {
+        if $fmt.alternate() {
+            $crate::__pad_args($fmt, ::core::format_args!($alt))
+        } else {
+            $crate::__pad_args($fmt, ::core::format_args!($format))
         }
     };
 
-    ($ty:ty: $($variant:ident { $($inner:ident),+ } => $format:literal),+ ,) => {
-        $crate::impl_display_enum!($ty: $($variant { $($inner),+ } => $format),+);
+    (@write $fmt:ident [] $variant:ident) => {
+        ::core::compile_error!(::core::concat!(
+            "missing format string for variant `",
+            ::core::stringify!($variant),
+            "`; expected `Variant => \"format\"` or a `case = style;` prefix",
+        ))
     };
 
-    (iou @ $ident:ident) => {
-        $ident
+    (@write $fmt:ident [$case:ident] $variant:ident) => {
+        $crate::__pad_args(
+            $fmt,
+            ::core::format_args!(
+                "{}",
+                $crate::__Cased(
+                    ::core::stringify!($variant),
+                    $crate::impl_display_enum!(@case $case),
+                ),
+            ),
+        )
     };
 
-    // IDENT-or-underscore
-    (iou @ $ident:ident) => {
-        $ident
+    (@case lower) => { $crate::__Case::Lower };
+    (@case snake) => { $crate::__Case::Snake };
+    (@case kebab) => { $crate::__Case::Kebab };
+    (@case screaming_snake) => { $crate::__Case::ScreamingSnake };
+    (@case camel) => { $crate::__Case::Camel };
+
+    (@case $case:ident) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown case `",
+            ::core::stringify!($case),
+            "`; expected one of `lower`, `snake`, `kebab`, `screaming_snake`, or `camel`",
+        ))
     };
 
-    // ident-or-UNDERSCORE
-    (iou @ _) => {
-        _
+    // the remaining variants of a `case = style;` prefix that was split off as a batch entry
+    (
+        @invalid
+        $first:ident $(($($inner:tt)*))? $({$($fields:tt)*})?
+        $(, $variant:ident $(($($rest_inner:tt)*))? $({$($rest_fields:tt)*})?)+ $(,)?
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "missing type before the variants; ",
+            "in a batch, end the `case = style` prefix with a comma, ",
+            "as in `Type: case = kebab, Variant, ...`",
+        ));
     };
 
+    (@invalid $(#[$attr:meta])* $this:ty, $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `:` after the type, ",
+            "as in `impl_display_enum!(Type: Variant => \"format\")`; ",
+            "the `,` delimiter was replaced in v0.2.0",
+        ));
+    };
 
-    // TODO: mixed named and positional variant support
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!(
+            "missing variants; expected `impl_display_enum!(Type: Variant => \"format\", ...)`"
+        );
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!(
+            "missing variants; expected `impl_display_enum!(Type: Variant => \"format\", ...)`"
+        );
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt : $($rest:tt)*) => {
+        ::core::compile_error!(
+            "missing variants; expected `impl_display_enum!(Type: Variant => \"format\", ...)`"
+        );
+    };
+
+    (@invalid @arms $ctx:tt $generics:tt $this:tt $where:tt [] case = $case:ident $(,)?) => {
+        ::core::compile_error!(::core::concat!(
+            "missing variants after `case = ",
+            ::core::stringify!($case),
+            "`; in a batch, end the prefix with a comma, as in `Type: case = ",
+            ::core::stringify!($case),
+            ", Variant, ...`",
+        ));
+    };
+
+    (@invalid @arms $ctx:tt $generics:tt $this:tt $where:tt $case:tt $($variants:tt)*) => {
+        $crate::impl_display_enum!(@find_invalid $($variants)*);
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!("expected `impl_display_enum!(Type: Variant => \"format\", ...)`");
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_display_enum $($input)*);
+    };
 }
 
 #[cfg(test)]
 mod tests {
     use alloc::{
         borrow::ToOwned as _,
+        format,
         string::{String, ToString as _},
     };
 
@@ -295,6 +671,99 @@ mod tests {
         assert_eq!(Foo { inner: 42 }.to_string(), "42");
     }
 
+    #[test]
+    fn impl_forward_field_paths() {
+        struct Foo(core::marker::PhantomData<()>, u64);
+
+        forward_display!(Foo => 1);
+
+        struct Inner {
+            value: u64,
+        }
+        struct Bar {
+            inner: Inner,
+        }
+
+        forward_display!(Bar => inner.value);
+
+        assert_eq!(Foo(core::marker::PhantomData, 42).to_string(), "42");
+        assert_eq!(
+            Bar {
+                inner: Inner { value: 42 }
+            }
+            .to_string(),
+            "42"
+        );
+    }
+
+    #[test]
+    fn impl_forward_generic_bounded_struct() {
+        struct Foo<T, U> {
+            inner: T,
+            _marker: core::marker::PhantomData<U>,
+        }
+
+        forward_display!(<T: core::fmt::Display, U> in Foo<T, U> => inner);
+
+        struct Bar<T>(T);
+
+        forward_display!(<T> in Bar<T> where T: core::fmt::Display);
+
+        let foo = Foo::<_, String> {
+            inner: 42,
+            _marker: core::marker::PhantomData,
+        };
+        assert_eq!(foo.to_string(), "42");
+        assert_eq!(Bar(42).to_string(), "42");
+    }
+
+    #[test]
+    fn impl_forward_field_type_bound() {
+        use core::marker::PhantomData;
+
+        struct Marker;
+
+        struct Foo<T, M>(T, PhantomData<M>);
+
+        forward_display!(<T, M> in Foo<T, M> => 0: T);
+
+        struct Bar<T, M> {
+            inner: (T, PhantomData<M>),
+        }
+
+        forward_display!(<T: Copy, M> in Bar<T, M> => inner.0: T);
+
+        struct Baz<T, M>(T, PhantomData<M>);
+
+        forward_display!(<T, M> in Baz<T, M> where T: core::fmt::Display => 0);
+
+        assert_eq!(Foo(42, PhantomData::<Marker>).to_string(), "42");
+        assert_eq!(
+            Bar {
+                inner: (42, PhantomData::<Marker>)
+            }
+            .to_string(),
+            "42"
+        );
+        assert_eq!(Baz(42, PhantomData::<Marker>).to_string(), "42");
+    }
+
+    #[test]
+    fn impl_forward_borrowed_newtype_struct() {
+        struct Foo<'a>(&'a str);
+
+        forward_display!(<'a> in Foo<'a>);
+
+        struct Bar<'a, T> {
+            inner: &'a T,
+        }
+
+        forward_display!(<'a, T: core::fmt::Display> in Bar<'a, T> => inner);
+
+        assert_eq!(Foo("hello world").to_string(), "hello world");
+        assert_eq!(Bar { inner: &42 }.to_string(), "42");
+    }
+
     #[test]
     fn impl_basic_for_unit_struct() {
         struct Foo;
@@ -318,6 +787,158 @@ mod tests {
         assert_eq!(Hello3.to_string(), "hello world");
     }
 
+    #[test]
+    fn impl_basic_formatter_flags() {
+        struct Foo;
+        impl_display!(Foo: "foo");
+
+        struct Bar;
+        impl_display!(Bar: "{}-{}", 4, 2);
+
+        // width
+        assert_eq!(format!("{:5}", Foo), "foo  ");
+        assert_eq!(format!("{:5}", Bar), "4-2  ");
+        assert_eq!(format!("{:2}", Bar), "4-2");
+
+        // fill and alignment
+        assert_eq!(format!("{:*<5}", Foo), "foo**");
+        assert_eq!(format!("{:>5}", Bar), "  4-2");
+        assert_eq!(format!("{:-^7}", Bar), "--4-2--");
+        assert_eq!(format!("{:-^6}", Bar), "-4-2--");
+
+        // precision
+        assert_eq!(format!("{:.2}", Foo), "fo");
+        assert_eq!(format!("{:.2}", Bar), "4-");
+        assert_eq!(format!("{:>4.2}", Bar), "  4-");
+    }
+
+    #[test]
+    fn impl_basic_alternate() {
+        const VERSION: &str = "1.0.0";
+
+        struct Foo;
+        impl_display!(Foo: "v1" | "version {VERSION}");
+
+        assert_eq!(format!("{}", Foo), "v1");
+        assert_eq!(format!("{:#}", Foo), "version 1.0.0");
+        assert_eq!(format!("{:>#8.3}", Foo), "     ver");
+    }
+
+    #[test]
+    fn impl_basic_destructured_fields() {
+        struct Span {
+            start: u32,
+            end: u32,
+        }
+        impl_display!(Span { start, end }: "{start}..{end}");
+        assert_eq!(Span { start: 2, end: 4 }.to_string(), "2..4");
+
+        struct Len {
+            start: u32,
+            end: u32,
+        }
+        impl_display!(Len { start, end }: "{} long", end - start,);
+        assert_eq!(Len { start: 2, end: 5 }.to_string(), "3 long");
+
+        struct Pair(u32, u32);
+        impl_display!(Pair(a, b): "({a}, {b})" | "pair of {a} and {b}");
+        assert_eq!(Pair(1, 2).to_string(), "(1, 2)");
+        assert_eq!(format!("{:#}", Pair(1, 2)), "pair of 1 and 2");
+
+        struct Third((), (), u8);
+        impl_display!(Third { 2: third, .. }: "{third}");
+        assert_eq!(format!("{:>3}", Third((), (), 3)), "  3");
+    }
+
+    #[test]
+    fn impl_basic_generic_destructured_fields() {
+        struct Labelled<'a, T> {
+            label: &'a str,
+            value: T,
+        }
+        impl_display!(
+            <'a, T: core::fmt::Display> in Labelled<'a, T> { label, value }:
+            "{label}: {value}"
+        );
+        assert_eq!(
+            Labelled {
+                label: "x",
+                value: 42
+            }
+            .to_string(),
+            "x: 42"
+        );
+
+        struct Pair<T>(T, T);
+        impl_display!(<T: core::fmt::Display> in Pair<T>(a, b): "({a}, {b})");
+        assert_eq!(Pair("a", "b").to_string(), "(a, b)");
+
+        struct Point<T>(T, T);
+        impl_display!(<T> in Point<T> where T: core::fmt::Display (x, y): "{x}/{y}");
+        assert_eq!(Point(1, 2).to_string(), "1/2");
+
+        struct Named<T> {
+            name: T,
+        }
+        impl_display!(<T> in Named<T> where T: core::fmt::Display { name }: "{name}");
+        assert_eq!(Named { name: "n" }.to_string(), "n");
+    }
+
+    #[test]
+    fn impl_basic_generic_with_where_clause() {
+        struct Foo<T>(core::marker::PhantomData<T>);
+        impl_display!(
+            <T> in Foo<T> where T: Default + core::fmt::Display:
+            "default: {}", T::default()
+        );
+        assert_eq!(
+            Foo::<u64>(core::marker::PhantomData).to_string(),
+            "default: 0"
+        );
+    }
+
+    #[test]
+    fn impl_enum_generic() {
+        enum Either<L, R> {
+            Left(L),
+            Right(R),
+        }
+
+        impl_display_enum!(
+            <L, R> in Either<L, R>
+            where
+                L: core::fmt::Display,
+                R: core::fmt::Display:
+            Left(l) => "left: {l}",
+            Right(r) => "right: {r}",
+        );
+
+        assert_eq!(Either::<_, u64>::Left("foo").to_string(), "left: foo");
+        assert_eq!(Either::<&str, _>::Right(42).to_string(), "right: 42");
+    }
+
+    #[test]
+    fn impl_enum_generic_marker() {
+        enum Event<T, M> {
+            Msg(T),
+            Tick(core::marker::PhantomData<M>),
+        }
+
+        impl_display_enum!(
+            <T: core::fmt::Display, M> in Event<T, M>:
+            Msg(msg) => "message: {msg}",
+            Tick(_) => "tick",
+        );
+
+        struct NotDisplay;
+
+        assert_eq!(Event::<_, NotDisplay>::Msg("hi").to_string(), "message: hi");
+        assert_eq!(
+            Event::<u8, NotDisplay>::Tick(core::marker::PhantomData).to_string(),
+            "tick"
+        );
+    }
+
     #[test]
     fn impl_enum_named_variant_with_trailing_comma() {
         enum Foo {
@@ -328,4 +949,225 @@ mod tests {
 
         assert_eq!(Foo::Bar { value: 42 }.to_string(), "42");
     }
+
+    #[test]
+    fn impl_enum_mixed_variants() {
+        enum Event {
+            Tick,
+            Key(char),
+            Move { x: i32, y: i32 },
+            Resize(u32, u32),
+            Closed,
+        }
+
+        impl_display_enum!(
+            Event:
+            Tick => "tick",
+            Key(key) => "key {key}",
+            Move { x, y } => "move to {x}, {y}",
+            Resize(width, height) => "resize to {width}x{height}",
+            Closed => "closed",
+        );
+
+        assert_eq!(Event::Tick.to_string(), "tick");
+        assert_eq!(Event::Key('q').to_string(), "key q");
+        assert_eq!(Event::Move { x: 1, y: -2 }.to_string(), "move to 1, -2");
+        assert_eq!(Event::Resize(80, 24).to_string(), "resize to 80x24");
+        assert_eq!(Event::Closed.to_string(), "closed");
+    }
+
+    #[test]
+    fn impl_enum_formatter_flags() {
+        enum Foo {
+            Bar,
+            Qux(u64),
+            Baz { msg: &'static str },
+        }
+
+        impl_display_enum!(Foo: Bar => "bar", Qux(n) => "qux {n}", Baz { msg } => "{msg}");
+
+        // width
+        assert_eq!(format!("{:5}", Foo::Bar), "bar  ");
+        assert_eq!(format!("{:7}", Foo::Qux(42)), "qux 42 ");
+        assert_eq!(format!("{:4}", Foo::Baz { msg: "hi" }), "hi  ");
+
+        // fill and alignment
+        assert_eq!(format!("{:>5}", Foo::Bar), "  bar");
+        assert_eq!(format!("{:_^10}", Foo::Qux(42)), "__qux 42__");
+        assert_eq!(format!("{:.>4}", Foo::Baz { msg: "hi" }), "..hi");
+
+        // precision
+        assert_eq!(format!("{:.2}", Foo::Bar), "ba");
+        assert_eq!(format!("{:.3}", Foo::Qux(42)), "qux");
+        assert_eq!(format!("{:<6.3}|", Foo::Qux(42)), "qux   |");
+        assert_eq!(format!("{:.1}", Foo::Baz { msg: "ünï" }), "ü");
+    }
+
+    #[test]
+    fn impl_enum_alternate() {
+        enum Foo {
+            Bar,
+            Qux,
+        }
+
+        impl_display_enum!(Foo: Bar => "bar" | "the bar", Qux => "qux",);
+
+        assert_eq!(format!("{}", Foo::Bar), "bar");
+        assert_eq!(format!("{:#}", Foo::Bar), "the bar");
+        assert_eq!(format!("{:#}", Foo::Qux), "qux");
+        assert_eq!(format!("{:<#9}|", Foo::Bar), "the bar  |");
+
+        enum Event {
+            Tick,
+            Key(char),
+            Move { x: i32, y: i32 },
+        }
+
+        impl_display_enum!(
+            Event:
+            Tick => "tick" | "clock ticked",
+            Key(key) => "{key}" | "key {key:?} pressed",
+            Move { x, y } => "move" | "moved to ({x}, {y})",
+        );
+
+        assert_eq!(format!("{}", Event::Tick), "tick");
+        assert_eq!(format!("{:#}", Event::Tick), "clock ticked");
+        assert_eq!(format!("{}", Event::Key('q')), "q");
+        assert_eq!(format!("{:#}", Event::Key('q')), "key 'q' pressed");
+        assert_eq!(format!("{}", Event::Move { x: 1, y: 2 }), "move");
+        assert_eq!(format!("{:#}", Event::Move { x: 1, y: 2 }), "moved to (1, 2)");
+    }
+
+    #[test]
+    fn impl_enum_case_conversion() {
+
+        enum Level {
+            Debug,
+            NotFound,
+            HTTPError,
+        }
+
+        impl_display_enum!(Level: case = lower, Debug, NotFound, HTTPError);
+        assert_eq!(Level::Debug.to_string(), "debug");
+        assert_eq!(Level::NotFound.to_string(), "notfound");
+        assert_eq!(Level::HTTPError.to_string(), "httperror");
+
+        enum Level2 {
+            Debug,
+            NotFound,
+            HTTPError,
+        }
+
+        impl_display_enum!(Level2: case = snake, Debug, NotFound, HTTPError,);
+        assert_eq!(Level2::Debug.to_string(), "debug");
+        assert_eq!(Level2::NotFound.to_string(), "not_found");
+        assert_eq!(Level2::HTTPError.to_string(), "http_error");
+
+        enum Level3 {
+            NotFound,
+        }
+
+        impl_display_enum!(Level3: case = kebab; NotFound);
+        assert_eq!(Level3::NotFound.to_string(), "not-found");
+
+        enum Level4 {
+            NotFound,
+        }
+
+        impl_display_enum!(Level4: case = screaming_snake, NotFound);
+        assert_eq!(Level4::NotFound.to_string(), "NOT_FOUND");
+
+        enum Level5 {
+            NotFound,
+        }
+
+        impl_display_enum!(Level5: case = camel, NotFound);
+        assert_eq!(Level5::NotFound.to_string(), "notFound");
+
+        enum Level6<T> {
+            NotFound,
+            Other(T),
+        }
+
+        impl_display_enum!(
+            <T> in Level6<T> where T: Copy:
+            case = kebab;
+            NotFound,
+            Other(..),
+        );
+        assert_eq!(Level6::<u8>::NotFound.to_string(), "not-found");
+        assert_eq!(Level6::Other(1).to_string(), "other");
+
+        enum Level7 {
+            Debug,
+            Info,
+            Warn,
+        }
+
+        impl_display_enum!(Level7: case = kebab; Debug, Info, Warn);
+        assert_eq!(Level7::Debug.to_string(), "debug");
+        assert_eq!(Level7::Info.to_string(), "info");
+        assert_eq!(Level7::Warn.to_string(), "warn");
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn impl_enum_case_conversion_overrides() {
+        enum Event {
+            KeyPress(char),
+            MouseMove { x: i32, y: i32 },
+            WindowClosed,
+            Resize(u32, u32),
+        }
+
+        impl_display_enum!(
+            Event: case = snake,
+            KeyPress(..),
+            MouseMove { .. },
+            WindowClosed => "closed" | "window closed",
+            Resize(w, h) => "resize to {w}x{h}",
+        );
+
+        assert_eq!(Event::KeyPress('q').to_string(), "key_press");
+        assert_eq!(Event::MouseMove { x: 1, y: 2 }.to_string(), "mouse_move");
+        assert_eq!(Event::WindowClosed.to_string(), "closed");
+        assert_eq!(format!("{:#}", Event::WindowClosed), "window closed");
+        assert_eq!(Event::Resize(80, 24).to_string(), "resize to 80x24");
+        assert_eq!(format!("[{:>11}]", Event::KeyPress('q')), "[  key_press]");
+    }
+
+    #[test]
+    fn impl_enum_many_variants() {
+        // a variant per recursion step would exceed the default recursion limit
+        macro_rules! many_variants {
+            ($($variant:ident)+) => {
+                enum Many {
+                    $($variant(u8),)+
+                    Unit,
+                    Named { code: u8 },
+                }
+
+                impl_display_enum! {
+                    Many:
+                    $($variant(n) => "{n}",)+
+                    Unit => "unit",
+                    Named { code } => "named {code}",
+                }
+
+                $(assert_eq!(Many::$variant(1).to_string(), "1");)+
+                assert_eq!(Many::Unit.to_string(), "unit");
+                assert_eq!(Many::Named { code: 2 }.to_string(), "named 2");
+            };
+        }
+
+        many_variants!(
+            V0 V1 V2 V3 V4 V5 V6 V7 V8 V9 V10 V11 V12 V13 V14 V15 V16 V17 V18 V19 V20 V21 V22 V23
+            V24 V25 V26 V27 V28 V29 V30 V31 V32 V33 V34 V35 V36 V37 V38 V39 V40 V41 V42 V43 V44 V45
+            V46 V47 V48 V49 V50 V51 V52 V53 V54 V55 V56 V57 V58 V59 V60 V61 V62 V63 V64 V65 V66 V67
+            V68 V69 V70 V71 V72 V73 V74 V75 V76 V77 V78 V79 V80 V81 V82 V83 V84 V85 V86 V87 V88 V89
+            V90 V91 V92 V93 V94 V95 V96 V97 V98 V99 V100 V101 V102 V103 V104 V105 V106 V107 V108
+            V109 V110 V111 V112 V113 V114 V115 V116 V117 V118 V119 V120 V121 V122 V123 V124 V125
+            V126 V127 V128 V129
+        );
+    }
 }
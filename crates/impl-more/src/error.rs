@@ -1,3 +1,78 @@
+/// Selects a field as an error source, dereferencing it if it is a smart pointer.
+///
+/// Fields whose target is an error, like `Box<dyn Error>` or `eyre::Report`, are dereferenced and
+/// other fields are used as the source directly. Invoked as `__error_source!(&self.field)`, and
+/// expands to a `&(dyn Error + 'static)` expression.
+///
+/// The helper traits are declared in the emitted block, as `core::error::Error` is not available
+/// on all Rust versions supported by this crate.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __error_source {
+    ($field:expr) => {{
+        struct Probe<'a, T: ?::core::marker::Sized>(&'a T);
+
+        trait AsDynError {
+            fn as_dyn_error(&self) -> &(dyn ::core::error::Error + 'static);
+        }
+
+        impl<E: ::core::error::Error + 'static> AsDynError for E {
+            fn as_dyn_error(&self) -> &(dyn ::core::error::Error + 'static) {
+                self
+            }
+        }
+
+        impl AsDynError for dyn ::core::error::Error + 'static {
+            fn as_dyn_error(&self) -> &(dyn ::core::error::Error + 'static) {
+                self
+            }
+        }
+
+        impl AsDynError for dyn ::core::error::Error + ::core::marker::Send + 'static {
+            fn as_dyn_error(&self) -> &(dyn ::core::error::Error + 'static) {
+                self
+            }
+        }
+
+        impl AsDynError
+            for dyn ::core::error::Error + ::core::marker::Send + ::core::marker::Sync + 'static
+        {
+            fn as_dyn_error(&self) -> &(dyn ::core::error::Error + 'static) {
+                self
+            }
+        }
+
+        // found without autoref, so smart pointers are dereferenced even if they are errors too
+        #[allow(dead_code)]
+        trait ViaDeref<'a> {
+            fn source(&self) -> &'a (dyn ::core::error::Error + 'static);
+        }
+
+        impl<'a, T> ViaDeref<'a> for Probe<'a, T>
+        where
+            T: ?::core::marker::Sized + ::core::ops::Deref,
+            T::Target: AsDynError,
+        {
+            fn source(&self) -> &'a (dyn ::core::error::Error + 'static) {
+                AsDynError::as_dyn_error(::core::ops::Deref::deref(self.0))
+            }
+        }
+
+        #[allow(dead_code)]
+        trait ViaError<'a> {
+            fn source(&self) -> &'a (dyn ::core::error::Error + 'static);
+        }
+
+        impl<'a, T: ::core::error::Error + 'static> ViaError<'a> for &Probe<'a, T> {
+            fn source(&self) -> &'a (dyn ::core::error::Error + 'static) {
+                self.0
+            }
+        }
+
+        (&Probe($field)).source()
+    }};
+}
+
 /// Implements [`Error`] for structs and forwards the `source` implementation to one of its fields.
 ///
 /// Emitted code is not compatible with `#[no_std]`.
@@ -38,26 +113,341 @@
 /// assert_eq!(err.source().unwrap().to_string(), "something went wrong");
 /// ```
 ///
-/// This macro does not yet support use with generic error wrappers.
+/// Fields holding smart pointers, like `eyre::Report` or `Box<dyn Error>`, are dereferenced to get
+/// the source, and fields holding a concrete error type are used as the source directly. Following
+/// the field with its type always uses the field itself and bounds the impl on that type:
+///
+/// ```
+/// use std::error::Error as _;
+///
+/// #[derive(Debug)]
+/// struct ConfigError(std::io::Error);
+///
+/// impl_more::forward_display!(ConfigError);
+/// impl_more::forward_error!(ConfigError);
+///
+/// #[derive(Debug)]
+/// struct ReadError {
+///     inner: std::io::Error,
+/// }
+///
+/// impl_more::forward_display!(ReadError => inner);
+/// impl_more::forward_error!(ReadError => inner: std::io::Error);
+///
+/// let err = ConfigError(std::io::Error::new(std::io::ErrorKind::NotFound, "no config"));
+/// assert!(err.source().unwrap().is::<std::io::Error>());
+/// ```
+///
+/// With `transparent(field)`, the wrapper is left out of the error chain: both [`Display`] and
+/// `source` are delegated to the field, so the field's own source becomes the wrapper's source. The
+/// [`Display`] impl is emitted by this macro and should not be added separately. The field is
+/// selected as above.
+///
+/// ```
+/// use std::error::Error as _;
+///
+/// #[derive(Debug)]
+/// struct Opaque(eyre::Report);
+///
+/// impl_more::forward_error!(Opaque => transparent(0));
+///
+/// let err = Opaque(eyre::eyre!("not found").wrap_err("failed to read config"));
+/// assert_eq!(err.to_string(), "failed to read config");
+/// assert_eq!(err.source().unwrap().to_string(), "not found");
+///
+/// #[derive(Debug)]
+/// struct ConfigError {
+///     inner: std::io::Error,
+/// }
+///
+/// impl_more::forward_error!(ConfigError => transparent(inner: std::io::Error));
+/// ```
+///
+/// For generic error wrappers (note that `Error + 'static` bounds are applied to all type
+/// parameters, unless the field's type is given or bounds are declared in the generics header):
+///
+/// ```
+/// use std::error::Error as _;
+///
+/// #[derive(Debug)]
+/// struct Context<E> {
+///     inner: E,
+///     ctx: &'static str,
+/// }
+///
+/// impl_more::impl_display!(<E> in Context<E> { ctx, .. }: "{ctx}");
+/// impl_more::forward_error!(<E> in Context<E> => inner: E);
+///
+/// let err = Context { inner: std::fmt::Error, ctx: "failed to render" };
+/// assert!(err.source().unwrap().is::<std::fmt::Error>());
+/// ```
 ///
 /// [`Error`]: core::error::Error
+/// [`Display`]: core::fmt::Display
 #[macro_export]
 macro_rules! forward_error {
-    ($ty:ty) => {
-        impl ::core::error::Error for $ty {
+    (
+        $(#[$attr:meta])*
+        <$($generic:ident),+> in $this:ty => transparent($($field:tt).+ : $source:ty)
+    ) => {
+        $crate::forward_error!(
+            @impl [$(#[$attr])*] [$($generic),+] [$this] [] => transparent($($field).+ : $source)
+        );
+    };
+
+    ($(#[$attr:meta])* <$($generic:ident),+> in $this:ty => $($field:tt).+ : $source:ty) => {
+        $crate::forward_error!(
+            @impl [$(#[$attr])*] [$($generic),+] [$this] [] => $($field).+ : $source
+        );
+    };
+
+    ($(#[$attr:meta])* <$($generic:ident),+> in $this:ty => $($rest:tt)*) => {
+        $crate::forward_error!(
+            @impl [$(#[$attr])*] [$($generic: ::core::error::Error + 'static),+] [$this] []
+            => $($rest)*
+        );
+    };
+
+    ($(#[$attr:meta])* <$($generic:ident),+> in $this:ty) => {
+        $crate::forward_error!(
+            @impl [$(#[$attr])*] [$($generic: ::core::error::Error + 'static),+] [$this] []
+        );
+    };
+
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(forward_error [$(#[$attr])*] [=>] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ty) => {
+        $crate::forward_error!(@impl [$(#[$attr])*] [] [$ty] []);
+    };
+
+    ($(#[$attr:meta])* $ty:ty => $($rest:tt)*) => {
+        $crate::forward_error!(@impl [$(#[$attr])*] [] [$ty] [] => $($rest)*);
+    };
+
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::error::Error for $ty where $($where)* {
+            #[inline]
+            fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+                Some($crate::__error_source!(&self.0))
+            }
+        }
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]
+        => transparent($($field:tt).+ : $source:ty)
+    ) => {
+        $crate::forward_display!(
+            @impl [$(#[$attr])*] [$($generics)*] [$ty] [$($where)*] => $($field).+ : $source
+        );
+
+        $(#[$attr])*
+        impl <$($generics)*> ::core::error::Error for $ty
+        where
+            $source: ::core::error::Error + 'static,
+            $($where)*
+        {
+            #[inline]
+            fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+                <$source as ::core::error::Error>::source(&self.$($field).+)
+            }
+        }
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]
+        => transparent($($field:tt).+)
+    ) => {
+        $crate::forward_display!(
+            @impl [$(#[$attr])*] [$($generics)*] [$ty] [$($where)*] => $($field).+
+        );
+
+        $(#[$attr])*
+        impl <$($generics)*> ::core::error::Error for $ty where $($where)* {
+            #[inline]
+            fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+                ::core::error::Error::source($crate::__error_source!(&self.$($field).+))
+            }
+        }
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]
+        => $($field:tt).+ : $source:ty
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::error::Error for $ty
+        where
+            $source: ::core::error::Error + 'static,
+            $($where)*
+        {
+            #[inline]
             fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
-                Some(::core::ops::Deref::deref(&self.0))
+                let source: &$source = &self.$($field).+;
+                Some(source)
             }
         }
     };
 
-    ($ty:ty => $field:ident) => {
-        impl ::core::error::Error for $ty {
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*] => $($field:tt).+) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::error::Error for $ty where $($where)* {
+            #[inline]
             fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
-                Some(::core::ops::Deref::deref(&self.$field))
+                Some($crate::__error_source!(&self.$($field).+))
             }
         }
     };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(
+            "expected `forward_error!(Type)`, `forward_error!(Type => field)`, \
+            `forward_error!(Type => field: SourceType)`, or \
+            `forward_error!(Type => transparent(field))`"
+        );
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(forward_error $($input)*);
+    };
+}
+
+/// Implements [`Display`] and [`Error`] for structs in one declaration.
+///
+/// Accepts the same input as [`impl_display!`], optionally followed by `, source = field`. The
+/// source field is selected as in [`forward_error!`], and its type can be given to bound it in
+/// generic impls. Without a source, a leaf error is implemented, as by [`impl_leaf_error!`].
+///
+/// Fields not listed in a destructuring pattern are ignored, so `Type { a }` need not end in `..`.
+/// A source cannot follow explicit format args; use fields from the pattern in the format string
+/// instead.
+///
+/// For generic types, the generics header is used verbatim, so any bounds required by the format
+/// args or for the type to implement [`Debug`] must be declared.
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error as _;
+///
+/// #[derive(Debug)]
+/// struct ParseFailed {
+///     line: usize,
+///     inner: std::num::ParseIntError,
+/// }
+///
+/// impl_more::impl_error!(ParseFailed { line }: "parse failed at line {line}", source = inner);
+///
+/// #[derive(Debug)]
+/// struct Timeout(u64);
+///
+/// impl_more::impl_error!(Timeout(secs): "timed out after {secs}s");
+///
+/// let err = ParseFailed { line: 3, inner: "x".parse::<u8>().unwrap_err() };
+/// assert_eq!(err.to_string(), "parse failed at line 3");
+/// assert!(err.source().unwrap().is::<std::num::ParseIntError>());
+///
+/// assert_eq!(Timeout(5).to_string(), "timed out after 5s");
+/// assert!(Timeout(5).source().is_none());
+/// ```
+///
+/// [`Display`]: core::fmt::Display
+/// [`Error`]: core::error::Error
+/// [`Debug`]: core::fmt::Debug
+/// [`impl_display!`]: crate::impl_display
+/// [`forward_error!`]: crate::forward_error
+/// [`impl_leaf_error!`]: crate::impl_leaf_error
+#[macro_export]
+macro_rules! impl_error {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_error [$(#[$attr])*] [:] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ident { $($fields:tt)* }: $($rest:tt)*) => {
+        $crate::impl_error!(@impl [$(#[$attr])*] [] [$ty] [] { $($fields)* } : $($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ident ($($fields:tt)*): $($rest:tt)*) => {
+        $crate::impl_error!(@impl [$(#[$attr])*] [] [$ty] [] ($($fields)*) : $($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ty: $($rest:tt)*) => {
+        $crate::impl_error!(@impl [$(#[$attr])*] [] [$ty] [] : $($rest)*);
+    };
+
+    // unlisted fields are ignored, so the source field need not be destructured
+    (@impl $ctx:tt $generics:tt $ty:tt $where:tt { $(..)? } : $($rest:tt)*) => {
+        $crate::impl_error!(@parts $ctx $generics $ty $where [{ .. }] : $($rest)*);
+    };
+
+    (
+        @impl $ctx:tt $generics:tt $ty:tt $where:tt
+        { $($field:ident $(: $bind:pat)?),+ $(, $(..)?)? } : $($rest:tt)*
+    ) => {
+        $crate::impl_error!(
+            @parts $ctx $generics $ty $where [{ $($field $(: $bind)?,)+ .. }] : $($rest)*
+        );
+    };
+
+    (@impl $ctx:tt $generics:tt $ty:tt $where:tt ($($fields:tt)*) : $($rest:tt)*) => {
+        $crate::impl_error!(@parts $ctx $generics $ty $where [($($fields)*)] : $($rest)*);
+    };
+
+    (@impl $ctx:tt $generics:tt $ty:tt $where:tt : $($rest:tt)*) => {
+        $crate::impl_error!(@parts $ctx $generics $ty $where [] : $($rest)*);
+    };
+
+    (
+        @parts $ctx:tt $generics:tt $ty:tt $where:tt $pattern:tt
+        : $format:literal $(| $alt:literal)? , source = transparent $($rest:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "`transparent` forwards `Display` and cannot be combined with a format string; ",
+            "use `forward_error!(Type => transparent(field))` instead",
+        ));
+    };
+
+    (
+        @parts $ctx:tt $generics:tt $ty:tt $where:tt $pattern:tt
+        : $format:literal $(| $alt:literal)? , source = $($field:tt).+ $(: $source:ty)? $(,)?
+    ) => {
+        $crate::impl_display!(@impl $ctx $generics $ty $where $pattern : $format $(| $alt)?);
+        $crate::forward_error!(@impl $ctx $generics $ty $where => $($field).+ $(: $source)?);
+    };
+
+    (
+        @parts $ctx:tt $generics:tt $ty:tt $where:tt $pattern:tt
+        : $format:literal | $alt:literal $(,)?
+    ) => {
+        $crate::impl_display!(@impl $ctx $generics $ty $where $pattern : $format | $alt);
+        $crate::impl_leaf_error!(@impl $ctx $generics $ty $where);
+    };
+
+    (
+        @parts $ctx:tt $generics:tt $ty:tt $where:tt $pattern:tt
+        : $format:literal $(, $args:expr)* $(,)?
+    ) => {
+        $crate::impl_display!(@impl $ctx $generics $ty $where $pattern : $format $(, $args)*);
+        $crate::impl_leaf_error!(@impl $ctx $generics $ty $where);
+    };
+
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!("missing format string; expected `impl_error!(Type: \"format\")`");
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `impl_error!(Type: \"format\")` or ",
+            "`impl_error!(Type: \"format\", source = field)`",
+        ));
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_error $($input)*);
+    };
 }
 
 /// Implements [`Error`] for enums.
@@ -76,53 +466,427 @@ macro_rules! forward_error {
 ///     Generic(String),
 /// }
 ///
-/// impl_more::impl_display_enum!(Err, Io(err) => "{err}", Generic(msg) => "{msg}");
-/// impl_more::impl_error_enum!(Err, Io(err) => err);
+/// impl_more::impl_display_enum!(Err: Io(err) => "{err}", Generic(msg) => "{msg}");
+/// impl_more::impl_error_enum!(Err: Io(err) => err);
 ///
 /// # let io_err = std::io::Error::new(std::io::ErrorKind::Other, "test");
 /// assert!(Err::Io(io_err).source().is_some());
 /// assert!(Err::Generic("oops".to_owned()).source().is_none());
 /// ```
 ///
+/// Tuple and struct variants can be mixed.
+///
+/// ```
+/// use std::error::Error as _;
+///
+/// #[derive(Debug)]
+/// enum Err {
+///     Io(std::io::Error),
+///     Parse { source: std::num::ParseIntError },
+/// }
+///
+/// impl_more::impl_display_enum! {
+///     Err:
+///     Io(err) => "{err}",
+///     Parse { source } => "invalid number",
+/// }
+/// impl_more::impl_error_enum! {
+///     Err:
+///     Io(err) => err,
+///     Parse { source } => source,
+/// }
+///
+/// # let io_err = std::io::Error::new(std::io::ErrorKind::Other, "test");
+/// assert!(Err::Io(io_err).source().is_some());
+/// assert!(Err::Parse { source: "x".parse::<u8>().unwrap_err() }.source().is_some());
+/// ```
+///
+/// For generic enums, the generics header is used verbatim, so parameters used as sources need
+/// `Error + 'static` bounds:
+///
+/// ```
+/// use std::error::Error;
+///
+/// #[derive(Debug)]
+/// enum Either<L, R> {
+///     Left(L),
+///     Right(R),
+/// }
+///
+/// impl_more::impl_display_enum!(
+///     <L: Error, R: Error> in Either<L, R>:
+///     Left(l) => "{l}",
+///     Right(r) => "{r}",
+/// );
+/// impl_more::impl_error_enum!(
+///     <L: Error + 'static, R: Error + 'static> in Either<L, R>:
+///     Left(l) => l,
+///     Right(r) => r,
+/// );
+///
+/// let err = Either::<std::fmt::Error, std::io::Error>::Left(std::fmt::Error);
+/// assert!(err.source().unwrap().is::<std::fmt::Error>());
+/// ```
+///
+/// Variants can also declare their message, in the same form as [`impl_display_enum!`], followed by
+/// an optional source. In this form, every variant is listed with a message and [`Display`] is
+/// implemented too; a list cannot mix variants with and without messages.
+///
+/// ```
+/// use std::error::Error as _;
+///
+/// #[derive(Debug)]
+/// enum Err {
+///     Io(std::io::Error),
+///     Parse { line: usize, source: std::num::ParseIntError },
+///     Unsupported,
+/// }
+///
+/// impl_more::impl_error_enum! {
+///     Err:
+///     Io(err) => "i/o error", source = err,
+///     Parse { line, source } => "invalid number at line {line}", source = source,
+///     Unsupported => "unsupported",
+/// }
+///
+/// let err = Err::Parse { line: 3, source: "x".parse::<u8>().unwrap_err() };
+/// assert_eq!(err.to_string(), "invalid number at line 3");
+/// assert!(err.source().is_some());
+/// assert!(Err::Unsupported.source().is_none());
+/// ```
+///
+/// In this form, a variant marked `transparent` is left out of the error chain: both [`Display`]
+/// and `source` are forwarded to the inner error.
+///
+/// ```
+/// use std::error::Error as _;
+///
+/// #[derive(Debug)]
+/// enum Err {
+///     Io(std::io::Error),
+///     Unsupported,
+/// }
+///
+/// impl_more::impl_error_enum! {
+///     Err:
+///     Io(err) => transparent err,
+///     Unsupported => "unsupported",
+/// }
+///
+/// let err = Err::Io(std::io::Error::new(std::io::ErrorKind::Other, "disk full"));
+/// assert_eq!(err.to_string(), "disk full");
+/// assert!(err.source().is_none());
+/// ```
+///
 /// [`Error`]: core::error::Error
+/// [`Display`]: core::fmt::Display
+/// [`impl_display_enum!`]: crate::impl_display_enum
 #[macro_export]
 macro_rules! impl_error_enum {
-    ($ty:ty: $($variant:ident ($($inner:ident),+) => $source:expr),+ ,) => {
-        impl ::core::error::Error for $ty {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_error_enum [$(#[$attr])*] [:] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ty: $($rest:tt)*) => {
+        $crate::impl_error_enum!(@impl [$(#[$attr])*] [] [$ty] [] : $($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ty) => {
+        $crate::impl_error_enum!(@impl [$(#[$attr])*] [] [$ty] []);
+    };
+
+    // variants with messages implement `Display` too
+    (
+        @impl $ctx:tt $generics:tt $ty:tt $where:tt :
+        $variant:ident $(($($inner:tt)*))? $({$($fields:tt)*})? => $format:literal $($rest:tt)*
+    ) => {
+        $crate::impl_error_enum!(
+            @combined $ctx $generics $ty $where
+            $variant $(($($inner)*))? $({$($fields)*})? => $format $($rest)*
+        );
+    };
+
+    (
+        @impl $ctx:tt $generics:tt $ty:tt $where:tt :
+        $variant:ident $(($($inner:tt)*))? $({$($fields:tt)*})? => transparent $($rest:tt)*
+    ) => {
+        $crate::impl_error_enum!(
+            @combined $ctx $generics $ty $where
+            $variant $(($($inner)*))? $({$($fields)*})? => transparent $($rest)*
+        );
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*] :
+        $($variant:ident $fields:tt => $source:expr),+ $(,)?
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::error::Error for $ty where $($where)* {
             fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> {
                 match self {
                     $(
-                        Self::$variant($($inner),+) => ::core::option::Option::Some($source),
-                    )*
+                        Self::$variant $fields => $crate::impl_error_enum!(@source $variant => $source),
+                    )+
+                    #[allow(unreachable_patterns)]
                     _ => ::core::option::Option::None,
                 }
             }
         }
     };
 
-    ($ty:ty: $($variant:ident ($($inner:ident),+) => $source:expr),+) => {
-        $crate::impl_error_enum!($ty: $($variant ($($inner),+) => $source),+ ,);
+    // finds the first invalid variant, only used to report errors
+    (@find_invalid $variant:ident $fields:tt => transparent $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "variant `",
+            ::core::stringify!($variant),
+            "` is `transparent`, which forwards `Display` too; list every variant with its message, ",
+            "as in `impl_error_enum!(Type: Variant(e) => transparent e, Other => \"format\")`",
+        ));
+    };
+
+    (@find_invalid $variant:ident => $format:literal $($rest:tt)*) => {
+        $crate::impl_error_enum!(@source $variant => $format)
+    };
+
+    (@find_invalid $variant:ident $fields:tt => $format:literal $($rest:tt)*) => {
+        $crate::impl_error_enum!(@source $variant => $format)
+    };
+
+    (@find_invalid $variant:ident $fields:tt => $source:expr $(, $($rest:tt)*)?) => {
+        $crate::impl_error_enum!(@find_invalid $($($rest)*)?);
+    };
+
+    (@find_invalid $next:tt $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "invalid variant `",
+            ::core::stringify!($next),
+            "`; expected variants of the form `Variant(a, b) => source` or ",
+            "`Variant { a, b } => source`",
+        ));
+    };
+
+    (@find_invalid) => {
+        ::core::compile_error!(
+            "expected `impl_error_enum!(Type: Variant(inner) => source, ...)`"
+        );
     };
 
-    ($ty:ty: $($variant:ident { $($inner:ident),+ } => $source:expr),+ ,) => {
-        impl ::core::error::Error for $ty {
+    // `source = expr` clauses are matched as entries of their own, so the whole list fits in one
+    // repetition; `@arms` then pairs each clause with the variant before it
+    (
+        @combined $ctx:tt $generics:tt $ty:tt $where:tt
+        $(
+            $head:ident $(($($inner:tt)*))? $({$($fields:tt)*})?
+            $(= $source:expr)?
+            $(=> $format:literal $(| $alt:literal)?)?
+            $(=> transparent $transparent:expr)?
+        ),+ $(,)?
+    ) => {
+        $crate::impl_error_enum!(
+            @arms $ctx $generics $ty $where
+            [$(
+                $head $(($($inner)*))? $({$($fields)*})?
+                $(= $source)?
+                $(=> $format $(| $alt)?)?
+                $(=> transparent $transparent)?
+            ),+]
+            $((
+                $(@source $source,)?
+                $(@variant [[] $head => $format $(| $alt)?] [])?
+                $(@variant [transparent $transparent] [transparent $head $transparent])?
+                [$head $(($($inner)*))? $({$($fields)*})?]
+            ))+
+        );
+    };
+
+    (
+        @arms [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*] $variants:tt
+        $(
+            (@variant [$($write:tt)*] [$($own:tt)*] [$($pattern:tt)*])
+            $((@source $source:expr, [source]))?
+        )+
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::fmt::Display for $ty where $($where)* {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $(
+                        #[allow(unused_variables)]
+                        Self::$($pattern)* => $crate::impl_error_enum!(@write fmt $($write)*),
+                    )+
+                }
+            }
+        }
+
+        $(#[$attr])*
+        impl <$($generics)*> ::core::error::Error for $ty where $($where)* {
             fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> {
                 match self {
                     $(
-                        Self::$variant { $($inner),+ } => ::core::option::Option::Some($source),
-                    )*
-                    _ => ::core::option::Option::None,
+                        #[allow(unused_variables)]
+                        Self::$($pattern)* => {
+                            $crate::impl_error_enum!(@source_of [$($own)*] [$($source)?])
+                        }
+                    )+
                 }
             }
         }
     };
 
-    ($ty:ty: $($variant:ident { $($inner:ident),+ } => $source:expr),+) => {
-        $crate::impl_error_enum!($ty: $($variant { $($inner),+ } => $source),+ ,);
+    (@write $fmt:ident transparent $source:expr) => {
+        ::core::fmt::Display::fmt($source, $fmt)
+    };
+
+    (@write $fmt:ident $($write:tt)*) => {
+        $crate::impl_display_enum!(@write $fmt $($write)*)
+    };
+
+    // a message in a list of sources is caught here, as it also parses as an expression
+    (@source $variant:ident => $format:literal) => {
+        ::core::compile_error!(::core::concat!(
+            "variant `",
+            ::core::stringify!($variant),
+            "` has a message but the first variant does not; ",
+            "either give every variant a message or none",
+        ))
+    };
+
+    (@source $variant:ident => $source:expr) => {
+        ::core::option::Option::Some($source)
+    };
+
+    (@source_of [] [$source:expr]) => {
+        ::core::option::Option::Some($source)
     };
 
-    ($ty:ty) => {
-        impl ::core::error::Error for $ty {}
+    (@source_of [] []) => {
+        ::core::option::Option::None
+    };
+
+    (@source_of [transparent $variant:ident $source:expr] []) => {
+        ::core::error::Error::source($source)
+    };
+
+    (@source_of [transparent $variant:ident $transparent:expr] [$source:expr]) => {
+        ::core::compile_error!(::core::concat!(
+            "variant `",
+            ::core::stringify!($variant),
+            "` is `transparent`, which forwards `source` to the inner error; ",
+            "remove the `source = ...` clause",
+        ))
+    };
+
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::error::Error for $ty where $($where)* {}
+    };
+
+    (@invalid $(#[$attr:meta])* $this:ty, $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `:` after the type, ",
+            "as in `impl_error_enum!(Type: Variant(inner) => inner)`; ",
+            "the `,` delimiter was replaced in v0.2.0",
+        ));
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt :) => {
+        ::core::compile_error!(
+            "missing variants; expected `impl_error_enum!(Type: Variant(inner) => source, ...)`"
+        );
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt : $($variants:tt)+) => {
+        $crate::impl_error_enum!(@find_invalid $($variants)+);
+    };
+
+    (@invalid @combined $ctx:tt $generics:tt $this:tt $where:tt $($variants:tt)*) => {
+        $crate::impl_error_enum!(@find_invalid_message $($variants)*);
+    };
+
+    (@invalid @arms $ctx:tt $generics:tt $this:tt $where:tt [$($variants:tt)*] $($rest:tt)*) => {
+        $crate::impl_error_enum!(@find_invalid_message $($variants)*);
+    };
+
+    // finds the first invalid variant in a list of messages, only used to report errors
+    (
+        @find_invalid_message
+        $variant:ident $(($($inner:tt)*))? $({$($fields:tt)*})? => $format:literal
+        $(| $alt:literal)? , source = transparent $($rest:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "`source = transparent` on variant `",
+            ::core::stringify!($variant),
+            "` would hide the inner error's message; ",
+            "use `Variant(e) => transparent e` to forward both `Display` and `source`",
+        ));
+    };
+
+    (
+        @find_invalid_message
+        $variant:ident $(($($inner:tt)*))? $({$($fields:tt)*})? => $format:literal
+        $(| $alt:literal)? , source = $source:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_error_enum!(@find_invalid_message $($($rest)*)?);
+    };
+
+    (
+        @find_invalid_message
+        $variant:ident $(($($inner:tt)*))? $({$($fields:tt)*})? => $format:literal
+        $(| $alt:literal)? $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_error_enum!(@find_invalid_message $($($rest)*)?);
+    };
+
+    (
+        @find_invalid_message
+        $variant:ident $(($($inner:tt)*))? $({$($fields:tt)*})? => transparent $source:expr
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_error_enum!(@find_invalid_message $($($rest)*)?);
+    };
+
+    // variants selecting only a source are not accepted in a list of messages
+    (
+        @find_invalid_message
+        $variant:ident $(($($inner:tt)*))? $({$($fields:tt)*})? => $($rest:tt)*
+    ) => {
+        ::core::compile_error!(::core::concat!(
+            "variant `",
+            ::core::stringify!($variant),
+            "` is missing a message but the first variant has one; ",
+            "either give every variant a message or none",
+        ));
+    };
+
+    (@find_invalid_message $next:tt $($rest:tt)*) => {
+        $crate::impl_error_enum!(@invalid_message $next);
+    };
+
+    (@find_invalid_message) => {
+        ::core::compile_error!(
+            "expected `impl_error_enum!(Type: Variant => \"format\", ...)`"
+        );
+    };
+
+    (@invalid_message $next:tt) => {
+        ::core::compile_error!(::core::concat!(
+            "invalid variant `",
+            ::core::stringify!($next),
+            "`; expected variants of the form `Variant => \"format\"`, ",
+            "`Variant(a, b) => \"format\", source = a`, ",
+            "`Variant { a, b } => \"format\", source = a`, or `Variant(e) => transparent e`",
+        ));
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `impl_error_enum!(Type)` or ",
+            "`impl_error_enum!(Type: Variant(inner) => source, ...)`",
+        ));
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_error_enum $($input)*);
     };
 }
 
@@ -136,22 +900,58 @@ macro_rules! impl_error_enum {
 /// #[derive(Debug)]
 /// struct LeafError;
 ///
-/// impl_more::impl_display!(LeafError; "leaf");
-/// impl_more::impl_error_enum!(LeafError);
+/// impl_more::impl_display!(LeafError: "leaf");
+/// impl_more::impl_leaf_error!(LeafError);
+/// ```
+///
+/// For generic types, the generics header is used verbatim, so it must declare any bounds needed
+/// for the type to implement [`Debug`] and [`Display`]:
+///
+/// ```
+/// #[derive(Debug)]
+/// struct InvalidValue<T>(T);
+///
+/// impl_more::impl_display!(<T> in InvalidValue<T>: "invalid value");
+/// impl_more::impl_leaf_error!(<T: std::fmt::Debug> in InvalidValue<T>);
+///
+/// let err: Box<dyn std::error::Error> = Box::new(InvalidValue(42));
+/// assert_eq!(err.to_string(), "invalid value");
 /// ```
 ///
 /// [`Error`]: core::error::Error
+/// [`Debug`]: core::fmt::Debug
+/// [`Display`]: core::fmt::Display
 #[macro_export]
 macro_rules! impl_leaf_error {
-    ($ty:ty) => {
-        impl ::core::error::Error for $ty {}
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_leaf_error [$(#[$attr])*] [:] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ty) => {
+        $crate::impl_leaf_error!(@impl [$(#[$attr])*] [] [$ty] []);
+    };
+
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::error::Error for $ty where $($where)* {}
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!("expected `impl_leaf_error!(Type)`");
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_leaf_error $($input)*);
     };
 }
 
 #[cfg(test)]
 #[rustversion::since(1.81)]
 mod tests {
-    use alloc::string::String;
+    use alloc::{
+        format,
+        string::{String, ToString as _},
+    };
     use core::error::Error as _;
 
     #[test]
@@ -214,6 +1014,537 @@ mod tests {
         assert!(Foo::Baz.source().is_none());
     }
 
+    #[test]
+    fn generic() {
+        #[derive(Debug)]
+        struct Leaf<T>(T);
+
+        impl_display!(<T> in Leaf<T>: "leaf");
+        impl_leaf_error!(<T: core::fmt::Debug> in Leaf<T>);
+
+        #[derive(Debug)]
+        struct Boxed<E>(alloc::boxed::Box<E>);
+
+        impl_display!(<E> in Boxed<E>: "boxed");
+        forward_error!(<E: core::error::Error + 'static> in Boxed<E>);
+
+        #[derive(Debug)]
+        enum Wrapped<E> {
+            Inner(E),
+            Other,
+        }
+
+        impl_display!(<E> in Wrapped<E>: "wrapped");
+        impl_error_enum!(
+            <E> in Wrapped<E> where E: core::error::Error + 'static:
+            Inner(err) => err
+        );
+
+        #[derive(Debug)]
+        struct Context<E> {
+            ctx: &'static str,
+            inner: E,
+        }
+
+        impl_error!(
+            <E> in Context<E> where E: core::error::Error + 'static { ctx }:
+            "{ctx}", source = inner
+        );
+
+        assert!(Leaf(42).source().is_none());
+        assert!(Boxed(alloc::boxed::Box::new(Leaf(42))).source().is_some());
+        assert!(Wrapped::Inner(Leaf(42)).source().is_some());
+        assert!(Wrapped::<Leaf<u8>>::Other.source().is_none());
+
+        let err = Context {
+            ctx: "reading",
+            inner: Leaf(42),
+        };
+        assert_eq!(err.to_string(), "reading");
+        assert!(err.source().unwrap().is::<Leaf<i32>>());
+    }
+
+    #[test]
+    fn generic_implicit_bounds() {
+        #[derive(Debug)]
+        struct Leaf<T>(T);
+
+        impl_display!(<T> in Leaf<T>: "leaf");
+        impl_leaf_error!(<T: core::fmt::Debug> in Leaf<T>);
+
+        #[derive(Debug)]
+        struct Context<E> {
+            inner: E,
+            ctx: &'static str,
+        }
+
+        impl_display!(<E> in Context<E> { ctx, .. }: "{ctx}");
+        forward_error!(<E> in Context<E> => inner: E);
+
+        #[derive(Debug)]
+        struct Boxed<E>(alloc::boxed::Box<E>);
+
+        impl_display!(<E> in Boxed<E>: "boxed");
+        forward_error!(<E> in Boxed<E>);
+
+        #[derive(Debug)]
+        struct Opaque<E>(alloc::boxed::Box<E>);
+
+        forward_error!(<E> in Opaque<E> => transparent(0));
+
+        let err = Context {
+            inner: Leaf(42),
+            ctx: "context",
+        };
+        assert_eq!(err.to_string(), "context");
+        assert!(err.source().unwrap().is::<Leaf<i32>>());
+
+        let err = Boxed(alloc::boxed::Box::new(Leaf(42)));
+        assert!(err.source().unwrap().is::<Leaf<i32>>());
+
+        let err = Opaque(alloc::boxed::Box::new(err));
+        assert_eq!(err.to_string(), "boxed");
+        assert!(err.source().unwrap().is::<Leaf<i32>>());
+    }
+
+    #[test]
+    fn field_paths() {
+        #[derive(Debug)]
+        struct Tagged(
+            core::marker::PhantomData<()>,
+            alloc::boxed::Box<dyn core::error::Error>,
+        );
+
+        impl_display!(Tagged: "tagged");
+        forward_error!(Tagged => 1);
+
+        #[derive(Debug)]
+        struct Inner {
+            cause: alloc::boxed::Box<dyn core::error::Error>,
+        }
+
+        #[derive(Debug)]
+        struct Outer {
+            inner: Inner,
+        }
+
+        impl_display!(Outer: "outer");
+        forward_error!(Outer => inner.cause);
+
+        let err = Tagged(
+            core::marker::PhantomData,
+            alloc::boxed::Box::new(core::fmt::Error),
+        );
+        assert!(err.source().is_some());
+
+        let err = Outer {
+            inner: Inner {
+                cause: alloc::boxed::Box::new(core::fmt::Error),
+            },
+        };
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn concrete_field() {
+        #[derive(Debug)]
+        struct Leaf;
+
+        impl_display!(Leaf: "leaf");
+        impl_leaf_error!(Leaf);
+
+        #[derive(Debug)]
+        struct Wrapper(Leaf);
+
+        impl_display!(Wrapper: "wrapper");
+        forward_error!(Wrapper => 0: Leaf);
+
+        #[derive(Debug)]
+        struct Outer {
+            inner: Wrapper,
+            io: std::io::Error,
+        }
+
+        impl_display!(Outer: "outer");
+        forward_error!(Outer => inner.0: Leaf);
+
+        #[derive(Debug)]
+        struct IoOuter(Outer);
+
+        impl_display!(IoOuter: "io");
+        forward_error!(IoOuter => 0.io: std::io::Error);
+
+        let source = Wrapper(Leaf).source().unwrap();
+        assert!(source.is::<Leaf>());
+        assert!(source.source().is_none());
+
+        let err = Outer {
+            inner: Wrapper(Leaf),
+            io: std::io::Error::new(std::io::ErrorKind::Other, "test"),
+        };
+        assert!(err.source().unwrap().is::<Leaf>());
+
+        let err = IoOuter(err);
+        assert!(err.source().unwrap().is::<std::io::Error>());
+    }
+
+    #[test]
+    fn untyped_field() {
+        #[derive(Debug)]
+        struct Leaf;
+
+        impl_display!(Leaf: "leaf");
+        impl_leaf_error!(Leaf);
+
+        #[derive(Debug)]
+        struct Wrapper(Leaf);
+
+        impl_display!(Wrapper: "wrapper");
+        forward_error!(Wrapper);
+
+        // smart pointers are dereferenced, even if they implement `Error` too
+        #[derive(Debug)]
+        struct Boxed {
+            inner: alloc::boxed::Box<Wrapper>,
+        }
+
+        impl_display!(Boxed: "boxed");
+        forward_error!(Boxed => inner);
+
+        #[derive(Debug)]
+        struct Shared(alloc::sync::Arc<dyn core::error::Error + Send + Sync>);
+
+        impl_display!(Shared: "shared");
+        forward_error!(Shared => 0);
+
+        assert!(Wrapper(Leaf).source().unwrap().is::<Leaf>());
+
+        let err = Boxed {
+            inner: alloc::boxed::Box::new(Wrapper(Leaf)),
+        };
+        assert!(err.source().unwrap().is::<Wrapper>());
+
+        let err = Shared(alloc::sync::Arc::new(Wrapper(Leaf)));
+        assert!(err.source().unwrap().is::<Wrapper>());
+    }
+
+    #[test]
+    fn transparent() {
+        #[derive(Debug)]
+        struct Leaf;
+
+        impl_display!(Leaf: "leaf");
+        impl_leaf_error!(Leaf);
+
+        #[derive(Debug)]
+        struct Wrapper(Leaf);
+
+        impl_display!(Wrapper: "wrapper");
+        forward_error!(Wrapper => 0: Leaf);
+
+        #[derive(Debug)]
+        struct Opaque(alloc::boxed::Box<dyn core::error::Error>);
+
+        forward_error!(Opaque => transparent(0));
+
+        #[derive(Debug)]
+        struct Typed {
+            inner: Wrapper,
+        }
+
+        forward_error!(Typed => transparent(inner: Wrapper));
+
+        // a field named `transparent` is forwarded to like any other
+        #[derive(Debug)]
+        struct Named {
+            transparent: alloc::boxed::Box<dyn core::error::Error>,
+        }
+
+        impl_display!(Named: "named");
+        forward_error!(Named => transparent);
+
+        let err = Opaque(alloc::boxed::Box::new(Wrapper(Leaf)));
+        assert_eq!(err.to_string(), "wrapper");
+        assert!(err.source().unwrap().is::<Leaf>());
+
+        let err = Typed {
+            inner: Wrapper(Leaf),
+        };
+        assert_eq!(err.to_string(), "wrapper");
+        assert!(err.source().unwrap().is::<Leaf>());
+
+        let err = Named {
+            transparent: alloc::boxed::Box::new(Leaf),
+        };
+        assert!(err.source().unwrap().is::<Leaf>());
+    }
+
+    #[test]
+    fn transparent_variant() {
+        #[derive(Debug)]
+        struct Leaf;
+
+        impl_display!(Leaf: "leaf");
+        impl_leaf_error!(Leaf);
+
+        #[derive(Debug)]
+        struct Wrapper(Leaf);
+
+        impl_display!(Wrapper: "wrapper");
+        forward_error!(Wrapper => 0: Leaf);
+
+        #[derive(Debug)]
+        enum Foo {
+            Bar(Wrapper),
+            Baz { source: Wrapper },
+            Qux(Wrapper),
+        }
+
+        impl_error_enum! {
+            Foo:
+            Bar(err) => transparent err,
+            Baz { source } => transparent source,
+            Qux(err) => "qux", source = err,
+        }
+
+        assert_eq!(Foo::Bar(Wrapper(Leaf)).to_string(), "wrapper");
+        assert!(Foo::Bar(Wrapper(Leaf)).source().unwrap().is::<Leaf>());
+        let err = Foo::Baz {
+            source: Wrapper(Leaf),
+        };
+        assert_eq!(err.to_string(), "wrapper");
+        assert!(err.source().unwrap().is::<Leaf>());
+        assert_eq!(Foo::Qux(Wrapper(Leaf)).to_string(), "qux");
+        assert!(Foo::Qux(Wrapper(Leaf)).source().unwrap().is::<Wrapper>());
+    }
+
+    #[test]
+    fn many_variants() {
+        #[derive(Debug)]
+        struct Leaf;
+
+        impl_display!(Leaf: "leaf");
+        impl_leaf_error!(Leaf);
+
+        // a variant per recursion step would exceed the default recursion limit
+        macro_rules! many_variants {
+            ($($variant:ident)+) => {
+                #[derive(Debug)]
+                enum Many {
+                    $($variant(Leaf),)+
+                    Named { source: Leaf },
+                }
+
+                impl_display!(Many: "many");
+                impl_error_enum! {
+                    Many:
+                    $($variant(err) => err,)+
+                    Named { source } => source,
+                }
+
+                $(assert!(Many::$variant(Leaf).source().unwrap().is::<Leaf>());)+
+                assert!(Many::Named { source: Leaf }.source().unwrap().is::<Leaf>());
+            };
+        }
+
+        many_variants!(
+            V0 V1 V2 V3 V4 V5 V6 V7 V8 V9 V10 V11 V12 V13 V14 V15 V16 V17 V18 V19 V20 V21 V22 V23
+            V24 V25 V26 V27 V28 V29 V30 V31 V32 V33 V34 V35 V36 V37 V38 V39 V40 V41 V42 V43 V44 V45
+            V46 V47 V48 V49 V50 V51 V52 V53 V54 V55 V56 V57 V58 V59 V60 V61 V62 V63 V64 V65 V66 V67
+            V68 V69 V70 V71 V72 V73 V74 V75 V76 V77 V78 V79 V80 V81 V82 V83 V84 V85 V86 V87 V88 V89
+            V90 V91 V92 V93 V94 V95 V96 V97 V98 V99 V100 V101 V102 V103 V104 V105 V106 V107 V108
+            V109 V110 V111 V112 V113 V114 V115 V116 V117 V118 V119 V120 V121 V122 V123 V124 V125
+            V126 V127 V128 V129
+        );
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn many_variants_with_messages() {
+        #[derive(Debug)]
+        struct Leaf;
+
+        impl_error!(Leaf: "leaf");
+
+        // a variant per recursion step would exceed the default recursion limit
+        macro_rules! many_variants {
+            ($($variant:ident)+) => {
+                #[derive(Debug)]
+                enum Many {
+                    $($variant(Leaf),)+
+                    Named { code: u8, extra: () },
+                    Unit,
+                }
+
+                impl_error_enum! {
+                    Many:
+                    $($variant(err) => "many", source = err,)+
+                    Named { code, .. } => "named {code}",
+                    Unit => "unit",
+                }
+
+                $(
+                    assert_eq!(Many::$variant(Leaf).to_string(), "many");
+                    assert!(Many::$variant(Leaf).source().unwrap().is::<Leaf>());
+                )+
+                let err = Many::Named { code: 1, extra: () };
+                assert_eq!(err.to_string(), "named 1");
+                assert!(err.source().is_none());
+                assert!(Many::Unit.source().is_none());
+            };
+        }
+
+        many_variants!(
+            V0 V1 V2 V3 V4 V5 V6 V7 V8 V9 V10 V11 V12 V13 V14 V15 V16 V17 V18 V19 V20 V21 V22 V23
+            V24 V25 V26 V27 V28 V29 V30 V31 V32 V33 V34 V35 V36 V37 V38 V39 V40 V41 V42 V43 V44 V45
+            V46 V47 V48 V49 V50 V51 V52 V53 V54 V55 V56 V57 V58 V59 V60 V61 V62 V63 V64 V65 V66 V67
+            V68 V69 V70 V71 V72 V73 V74 V75 V76 V77 V78 V79 V80 V81 V82 V83 V84 V85 V86 V87 V88 V89
+            V90 V91 V92 V93 V94 V95 V96 V97 V98 V99 V100 V101 V102 V103 V104 V105 V106 V107 V108
+            V109 V110 V111 V112 V113 V114 V115 V116 V117 V118 V119 V120 V121 V122 V123 V124 V125
+            V126 V127 V128 V129
+        );
+    }
+
+    #[test]
+    fn display_and_error() {
+        #[derive(Debug)]
+        struct Leaf;
+
+        #[derive(Debug)]
+        struct Timeout(u64);
+
+        impl_error!(Leaf: "leaf");
+
+        #[derive(Debug)]
+        struct ParseFailed {
+            line: usize,
+            inner: Leaf,
+        }
+
+        impl_error!(ParseFailed { line }: "parse failed at line {line}", source = inner);
+
+        #[derive(Debug)]
+        struct Wrapped(alloc::boxed::Box<dyn core::error::Error>);
+
+        #[derive(Debug)]
+        struct Context<E> {
+            inner: E,
+            ctx: &'static str,
+        }
+
+        #[derive(Debug)]
+        struct Unavailable;
+
+        impl_error! {
+            <E: core::fmt::Debug> in Context<E> { ctx, .. }: "{ctx}", source = inner: E;
+            Wrapped(inner): "wrapped: {inner}", source = 0;
+            Timeout(secs): "timed out after {secs}s";
+            Unavailable: "unavailable" | "service unavailable",
+        }
+
+        assert_eq!(Leaf.to_string(), "leaf");
+        assert!(Leaf.source().is_none());
+
+        let err = ParseFailed {
+            line: 3,
+            inner: Leaf,
+        };
+        assert_eq!(err.to_string(), "parse failed at line 3");
+        assert!(err.source().unwrap().is::<Leaf>());
+
+        let err = Wrapped(alloc::boxed::Box::new(Leaf));
+        assert_eq!(err.to_string(), "wrapped: leaf");
+        assert!(err.source().unwrap().is::<Leaf>());
+
+        let err = Context {
+            inner: Leaf,
+            ctx: "context",
+        };
+        assert_eq!(err.to_string(), "context");
+        assert!(err.source().unwrap().is::<Leaf>());
+
+        assert_eq!(Timeout(5).to_string(), "timed out after 5s");
+        assert!(Timeout(5).source().is_none());
+
+        assert_eq!(format!("{:#}", Unavailable), "service unavailable");
+        assert!(Unavailable.source().is_none());
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn display_and_error_enum() {
+        #[derive(Debug)]
+        struct Leaf;
+
+        impl_error!(Leaf: "leaf");
+
+        #[derive(Debug)]
+        struct Wrapper(Leaf);
+
+        impl_error!(Wrapper: "wrapper", source = 0: Leaf);
+
+        #[derive(Debug)]
+        enum Foo {
+            Bar,
+            Baz(Leaf),
+            Qux { code: u8, source: Wrapper },
+            Quux(Wrapper),
+            Corge(u8, ()),
+            Grault { code: u8, extra: () },
+        }
+
+        impl_error_enum! {
+            Foo:
+            Bar => "bar" | "bar error",
+            Baz(err) => "baz", source = err,
+            Qux { code, source } => "qux {code}: {source}", source = source,
+            Quux(err) => transparent err,
+            Corge(a, _) => "corge {a}",
+            Grault { code, .. } => "grault {code}",
+        }
+
+        #[derive(Debug)]
+        enum Either<L, R> {
+            Left(L),
+            Right(R),
+        }
+
+        impl_error_enum! {
+            <L: core::error::Error + 'static, R: core::error::Error + 'static> in Either<L, R>:
+            Left(l) => "left: {l}", source = l,
+            Right(r) => "right: {r}", source = r
+        }
+
+        assert_eq!(Foo::Bar.to_string(), "bar");
+        assert_eq!(format!("{:#}", Foo::Bar), "bar error");
+        assert!(Foo::Bar.source().is_none());
+
+        assert_eq!(Foo::Baz(Leaf).to_string(), "baz");
+        assert!(Foo::Baz(Leaf).source().unwrap().is::<Leaf>());
+
+        let err = Foo::Qux {
+            code: 4,
+            source: Wrapper(Leaf),
+        };
+        assert_eq!(err.to_string(), "qux 4: wrapper");
+        assert!(err.source().unwrap().is::<Wrapper>());
+
+        assert_eq!(Foo::Quux(Wrapper(Leaf)).to_string(), "wrapper");
+        assert!(Foo::Quux(Wrapper(Leaf)).source().unwrap().is::<Leaf>());
+
+        assert_eq!(Foo::Corge(1, ()).to_string(), "corge 1");
+        assert!(Foo::Corge(1, ()).source().is_none());
+
+        let err = Foo::Grault { code: 2, extra: () };
+        assert_eq!(err.to_string(), "grault 2");
+        assert!(err.source().is_none());
+
+        let err = Either::<Leaf, Wrapper>::Right(Wrapper(Leaf));
+        assert_eq!(err.to_string(), "right: wrapper");
+        assert!(err.source().unwrap().is::<Wrapper>());
+        assert!(Either::<Leaf, Wrapper>::Left(Leaf).source().is_some());
+    }
+
     #[test]
     fn uniform_enum() {
         #[derive(Debug)]
@@ -0,0 +1,571 @@
+/// Implements a [`core::fmt`] trait for structs by forwarding to one of its fields.
+///
+/// Shared implementation of [`forward_display!`] and the other `forward_*!` formatting macros,
+/// invoked as `__forward_fmt!(macro_name Trait input...)`.
+///
+/// [`forward_display!`]: crate::forward_display
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __forward_fmt {
+    (
+        $mac:ident $trait:ident $(#[$attr:meta])*
+        <$($generic:ident),+> in $this:ty => $($field:tt).+ : $field_ty:ty
+    ) => {
+        $crate::__forward_fmt!(
+            $mac $trait @impl [$(#[$attr])*] [$($generic),+] [$this] [] => $($field).+ : $field_ty
+        );
+    };
+
+    (
+        $mac:ident $trait:ident $(#[$attr:meta])*
+        <$($generic:ident),+> in $this:ty => $($rest:tt)*
+    ) => {
+        $crate::__forward_fmt!(
+            $mac $trait
+            @impl [$(#[$attr])*] [$($generic: ::core::fmt::$trait),+] [$this] [] => $($rest)*
+        );
+    };
+
+    ($mac:ident $trait:ident $(#[$attr:meta])* <$($generic:ident),+> in $this:ty) => {
+        $crate::__forward_fmt!(
+            $mac $trait @impl [$(#[$attr])*] [$($generic: ::core::fmt::$trait),+] [$this] []
+        );
+    };
+
+    ($mac:ident $trait:ident $(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!($mac [$(#[$attr])*] [=>] <$($rest)*);
+    };
+
+    ($mac:ident $trait:ident $(#[$attr:meta])* $ty:ty) => {
+        $crate::__forward_fmt!($mac $trait @impl [$(#[$attr])*] [] [$ty] []);
+    };
+
+    ($mac:ident $trait:ident $(#[$attr:meta])* $ty:ty => $($rest:tt)*) => {
+        $crate::__forward_fmt!($mac $trait @impl [$(#[$attr])*] [] [$ty] [] => $($rest)*);
+    };
+
+    (
+        $mac:ident $trait:ident
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]
+        => $($field:tt).+ : $field_ty:ty
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::fmt::$trait for $ty
+        where
+            $field_ty: ::core::fmt::$trait,
+            $($where)*
+        {
+            #[inline]
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::$trait::fmt(&self.$($field).+, fmt)
+            }
+        }
+    };
+
+    (
+        $mac:ident $trait:ident
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*] => $($field:tt).+
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::fmt::$trait for $ty where $($where)* {
+            #[inline]
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::$trait::fmt(&self.$($field).+, fmt)
+            }
+        }
+    };
+
+    (
+        $mac:ident $trait:ident
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$ty:ty] [$($where:tt)*]
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::fmt::$trait for $ty where $($where)* {
+            #[inline]
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::$trait::fmt(&self.0, fmt)
+            }
+        }
+    };
+
+    ($mac:ident $trait:ident @invalid $($input:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `",
+            ::core::stringify!($mac),
+            "!(Type)` or `",
+            ::core::stringify!($mac),
+            "!(Type => field)`",
+        ));
+    };
+
+    ($mac:ident $trait:ident $($input:tt)*) => {
+        $crate::__batch!($mac $($input)*);
+    };
+}
+
+/// Implements [`Debug`] for structs by forwarding to one of its fields.
+///
+/// Accepts the same input as [`forward_display!`], including field paths, generics, and bounds.
+///
+/// # Examples
+///
+/// ```
+/// struct Id(u64);
+///
+/// impl_more::forward_debug!(Id);
+///
+/// assert_eq!(format!("{:?}", Id(42)), "42");
+/// ```
+///
+/// [`Debug`]: core::fmt::Debug
+/// [`forward_display!`]: crate::forward_display
+#[macro_export]
+macro_rules! forward_debug {
+    ($($input:tt)*) => {
+        $crate::__forward_fmt!(forward_debug Debug $($input)*);
+    };
+}
+
+/// Implements [`LowerHex`] for structs by forwarding to one of its fields.
+///
+/// Accepts the same input as [`forward_display!`], including field paths, generics, and bounds.
+///
+/// # Examples
+///
+/// ```
+/// struct Hash(u32);
+///
+/// impl_more::forward_lower_hex!(Hash);
+///
+/// assert_eq!(format!("{:08x}", Hash(0xbeef)), "0000beef");
+/// ```
+///
+/// [`LowerHex`]: core::fmt::LowerHex
+/// [`forward_display!`]: crate::forward_display
+#[macro_export]
+macro_rules! forward_lower_hex {
+    ($($input:tt)*) => {
+        $crate::__forward_fmt!(forward_lower_hex LowerHex $($input)*);
+    };
+}
+
+/// Implements [`UpperHex`] for structs by forwarding to one of its fields.
+///
+/// Accepts the same input as [`forward_display!`], including field paths, generics, and bounds.
+///
+/// # Examples
+///
+/// ```
+/// struct Hash(u32);
+///
+/// impl_more::forward_upper_hex!(Hash);
+///
+/// assert_eq!(format!("{:#X}", Hash(0xbeef)), "0xBEEF");
+/// ```
+///
+/// [`UpperHex`]: core::fmt::UpperHex
+/// [`forward_display!`]: crate::forward_display
+#[macro_export]
+macro_rules! forward_upper_hex {
+    ($($input:tt)*) => {
+        $crate::__forward_fmt!(forward_upper_hex UpperHex $($input)*);
+    };
+}
+
+/// Implements [`Binary`] for structs by forwarding to one of its fields.
+///
+/// Accepts the same input as [`forward_display!`], including field paths, generics, and bounds.
+///
+/// # Examples
+///
+/// ```
+/// struct Flags {
+///     bits: u8,
+/// }
+///
+/// impl_more::forward_binary!(Flags => bits);
+///
+/// assert_eq!(format!("{:08b}", Flags { bits: 5 }), "00000101");
+/// ```
+///
+/// [`Binary`]: core::fmt::Binary
+/// [`forward_display!`]: crate::forward_display
+#[macro_export]
+macro_rules! forward_binary {
+    ($($input:tt)*) => {
+        $crate::__forward_fmt!(forward_binary Binary $($input)*);
+    };
+}
+
+/// Implements [`Octal`] for structs by forwarding to one of its fields.
+///
+/// Accepts the same input as [`forward_display!`], including field paths, generics, and bounds.
+///
+/// # Examples
+///
+/// ```
+/// struct Mode(u32);
+///
+/// impl_more::forward_octal!(Mode);
+///
+/// assert_eq!(format!("{:o}", Mode(0o755)), "755");
+/// ```
+///
+/// [`Octal`]: core::fmt::Octal
+/// [`forward_display!`]: crate::forward_display
+#[macro_export]
+macro_rules! forward_octal {
+    ($($input:tt)*) => {
+        $crate::__forward_fmt!(forward_octal Octal $($input)*);
+    };
+}
+
+/// Implements [`LowerExp`] for structs by forwarding to one of its fields.
+///
+/// Accepts the same input as [`forward_display!`], including field paths, generics, and bounds.
+///
+/// # Examples
+///
+/// ```
+/// struct Meters(f64);
+///
+/// impl_more::forward_lower_exp!(Meters);
+///
+/// assert_eq!(format!("{:.1e}", Meters(1500.0)), "1.5e3");
+/// ```
+///
+/// [`LowerExp`]: core::fmt::LowerExp
+/// [`forward_display!`]: crate::forward_display
+#[macro_export]
+macro_rules! forward_lower_exp {
+    ($($input:tt)*) => {
+        $crate::__forward_fmt!(forward_lower_exp LowerExp $($input)*);
+    };
+}
+
+/// Implements [`UpperExp`] for structs by forwarding to one of its fields.
+///
+/// Accepts the same input as [`forward_display!`], including field paths, generics, and bounds.
+///
+/// # Examples
+///
+/// ```
+/// struct Meters(f64);
+///
+/// impl_more::forward_upper_exp!(Meters);
+///
+/// assert_eq!(format!("{:E}", Meters(1500.0)), "1.5E3");
+/// ```
+///
+/// [`UpperExp`]: core::fmt::UpperExp
+/// [`forward_display!`]: crate::forward_display
+#[macro_export]
+macro_rules! forward_upper_exp {
+    ($($input:tt)*) => {
+        $crate::__forward_fmt!(forward_upper_exp UpperExp $($input)*);
+    };
+}
+
+/// Implements [`Pointer`] for structs by forwarding to one of its fields.
+///
+/// Accepts the same input as [`forward_display!`], including field paths, generics, and bounds.
+///
+/// # Examples
+///
+/// ```
+/// struct Handle<'a>(&'a u8);
+///
+/// impl_more::forward_pointer!(<'a> in Handle<'a>);
+///
+/// let byte = 0;
+/// assert_eq!(format!("{:p}", Handle(&byte)), format!("{:p}", &byte));
+/// ```
+///
+/// [`Pointer`]: core::fmt::Pointer
+/// [`forward_display!`]: crate::forward_display
+#[macro_export]
+macro_rules! forward_pointer {
+    ($($input:tt)*) => {
+        $crate::__forward_fmt!(forward_pointer Pointer $($input)*);
+    };
+}
+
+/// Implements several [`core::fmt`] traits for structs by forwarding to one of its fields.
+///
+/// The type is followed by `=>` and a list of traits, as in `forward_fmt!(Type => Display, Debug)`.
+/// To forward to a field other than that of a newtype, the list is bracketed and followed by the
+/// field, as in `forward_fmt!(Type => [Display, Debug] field)`. The type and field accept the same
+/// input as [`forward_display!`]. Each trait is implemented using the corresponding `forward_*!`
+/// macro:
+///
+/// | Trait      | Implemented using        |
+/// |------------|--------------------------|
+/// | `Display`  | [`forward_display!`]     |
+/// | `Debug`    | [`forward_debug!`]       |
+/// | `LowerHex` | [`forward_lower_hex!`]   |
+/// | `UpperHex` | [`forward_upper_hex!`]   |
+/// | `Binary`   | [`forward_binary!`]      |
+/// | `Octal`    | [`forward_octal!`]       |
+/// | `LowerExp` | [`forward_lower_exp!`]   |
+/// | `UpperExp` | [`forward_upper_exp!`]   |
+/// | `Pointer`  | [`forward_pointer!`]     |
+///
+/// # Examples
+///
+/// ```
+/// struct Hash(u32);
+/// impl_more::forward_fmt!(Hash => Display, Debug, LowerHex, UpperHex);
+///
+/// let hash = Hash(255);
+/// assert_eq!(format!("{} {:?} {:x} {:X}", hash, hash, hash, hash), "255 255 ff FF");
+///
+/// struct Checksum<T> {
+///     value: T,
+/// }
+/// impl_more::forward_fmt!(<T> in Checksum<T> => [Display, LowerHex] value);
+///
+/// assert_eq!(format!("{:#06x}", Checksum { value: 255u8 }), "0x00ff");
+/// ```
+///
+/// [`forward_display!`]: crate::forward_display
+/// [`forward_debug!`]: crate::forward_debug
+/// [`forward_lower_hex!`]: crate::forward_lower_hex
+/// [`forward_upper_hex!`]: crate::forward_upper_hex
+/// [`forward_binary!`]: crate::forward_binary
+/// [`forward_octal!`]: crate::forward_octal
+/// [`forward_lower_exp!`]: crate::forward_lower_exp
+/// [`forward_upper_exp!`]: crate::forward_upper_exp
+/// [`forward_pointer!`]: crate::forward_pointer
+#[macro_export]
+macro_rules! forward_fmt {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(forward_fmt [$(#[$attr])*] [=>] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $ty:ty => [$($traits:tt)*] $($($field:tt).+ $(: $field_ty:ty)?)?) => {
+        $crate::forward_fmt!(
+            @traits [$(#[$attr])* $ty] [$($traits)*] [$(=> $($field).+ $(: $field_ty)?)?]
+        );
+    };
+
+    ($(#[$attr:meta])* $ty:ty => $($trait:ident),+ $(,)?) => {
+        $crate::forward_fmt!(@traits [$(#[$attr])* $ty] [$($trait),+] []);
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] []
+        => [$($traits:tt)*] $($($field:tt).+ $(: $field_ty:ty)?)?
+    ) => {
+        $crate::forward_fmt!(
+            @traits [$(#[$attr])* <$($generics)*> in $this] [$($traits)*]
+            [$(=> $($field).+ $(: $field_ty)?)?]
+        );
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)+]
+        => [$($traits:tt)*] $($($field:tt).+ $(: $field_ty:ty)?)?
+    ) => {
+        $crate::forward_fmt!(
+            @traits [$(#[$attr])* <$($generics)*> in $this where $($where)+] [$($traits)*]
+            [$(=> $($field).+ $(: $field_ty)?)?]
+        );
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] []
+        => $($trait:ident),+ $(,)?
+    ) => {
+        $crate::forward_fmt!(@traits [$(#[$attr])* <$($generics)*> in $this] [$($trait),+] []);
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)+]
+        => $($trait:ident),+ $(,)?
+    ) => {
+        $crate::forward_fmt!(
+            @traits [$(#[$attr])* <$($generics)*> in $this where $($where)+] [$($trait),+] []
+        );
+    };
+
+    // each trait is implemented by its own forwarding macro, which applies any implicit bounds
+    (@traits $head:tt [$($trait:ident),+ $(,)?] $field:tt) => {
+        $($crate::forward_fmt!(@trait $trait $head $field);)+
+    };
+
+    (@trait Display [$($head:tt)*] [$($field:tt)*]) => {
+        $crate::forward_display!($($head)* $($field)*);
+    };
+
+    (@trait Debug [$($head:tt)*] [$($field:tt)*]) => {
+        $crate::forward_debug!($($head)* $($field)*);
+    };
+
+    (@trait LowerHex [$($head:tt)*] [$($field:tt)*]) => {
+        $crate::forward_lower_hex!($($head)* $($field)*);
+    };
+
+    (@trait UpperHex [$($head:tt)*] [$($field:tt)*]) => {
+        $crate::forward_upper_hex!($($head)* $($field)*);
+    };
+
+    (@trait Binary [$($head:tt)*] [$($field:tt)*]) => {
+        $crate::forward_binary!($($head)* $($field)*);
+    };
+
+    (@trait Octal [$($head:tt)*] [$($field:tt)*]) => {
+        $crate::forward_octal!($($head)* $($field)*);
+    };
+
+    (@trait LowerExp [$($head:tt)*] [$($field:tt)*]) => {
+        $crate::forward_lower_exp!($($head)* $($field)*);
+    };
+
+    (@trait UpperExp [$($head:tt)*] [$($field:tt)*]) => {
+        $crate::forward_upper_exp!($($head)* $($field)*);
+    };
+
+    (@trait Pointer [$($head:tt)*] [$($field:tt)*]) => {
+        $crate::forward_pointer!($($head)* $($field)*);
+    };
+
+    (@trait $trait:ident $head:tt $field:tt) => {
+        ::core::compile_error!(::core::concat!(
+            "unsupported trait `",
+            ::core::stringify!($trait),
+            "` in `forward_fmt!`; expected one of `Display`, `Debug`, `LowerHex`, `UpperHex`, ",
+            "`Binary`, `Octal`, `LowerExp`, `UpperExp`, or `Pointer`; ",
+            "to forward to a field, bracket the traits, as in `Type => [Display] field`",
+        ));
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(
+            "expected `forward_fmt!(Type => Trait, ...)` or \
+            `forward_fmt!(Type => [Trait, ...] field)`"
+        );
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(forward_fmt $($input)*);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use core::marker::PhantomData;
+
+    #[test]
+    fn forward_debug() {
+        struct Foo(&'static str);
+        forward_debug!(Foo);
+
+        struct Bar {
+            inner: Foo,
+        }
+        forward_debug!(Bar => inner);
+
+        assert_eq!(format!("{:?}", Foo("foo")), r#""foo""#);
+        assert_eq!(format!("{:?}", Bar { inner: Foo("bar") }), r#""bar""#);
+    }
+
+    #[test]
+    fn forward_integer_fmts() {
+        struct Hash(u32);
+        forward_lower_hex!(Hash);
+        forward_upper_hex!(Hash);
+        forward_binary!(Hash);
+        forward_octal!(Hash);
+
+        assert_eq!(format!("{:x}", Hash(0xbeef)), "beef");
+        assert_eq!(format!("{:#010x}", Hash(0xbeef)), "0x0000beef");
+        assert_eq!(format!("{:X}", Hash(0xbeef)), "BEEF");
+        assert_eq!(format!("{:b}", Hash(5)), "101");
+        assert_eq!(format!("{:#o}", Hash(8)), "0o10");
+    }
+
+    #[test]
+    fn forward_float_fmts() {
+        struct Meters {
+            value: f64,
+        }
+        forward_lower_exp!(Meters => value);
+        forward_upper_exp!(Meters => value);
+
+        assert_eq!(format!("{:e}", Meters { value: 1500.0 }), "1.5e3");
+        assert_eq!(format!("{:.2E}", Meters { value: 1500.0 }), "1.50E3");
+    }
+
+    #[test]
+    fn forward_pointer() {
+        struct Handle<'a>(&'a u8);
+        forward_pointer!(<'a> in Handle<'a>);
+
+        let byte = 0;
+        assert_eq!(format!("{:p}", Handle(&byte)), format!("{:p}", &byte));
+    }
+
+    #[test]
+    fn forward_generic() {
+        struct Marker;
+
+        struct Id<T>(T);
+        forward_debug!(<T> in Id<T>);
+        forward_lower_hex!(<T> in Id<T>);
+
+        struct Tagged<T, M>(T, PhantomData<M>);
+        forward_lower_hex!(<T, M> in Tagged<T, M> => 0: T);
+        forward_debug! { <T, M> in Tagged<T, M> where T: core::fmt::Debug => 0 }
+
+        assert_eq!(format!("{:?}", Id("id")), r#""id""#);
+        assert_eq!(format!("{:x}", Id(255)), "ff");
+        assert_eq!(format!("{:x}", Tagged(255, PhantomData::<Marker>)), "ff");
+        assert_eq!(format!("{:?}", Tagged(1, PhantomData::<Marker>)), "1");
+    }
+
+    #[test]
+    fn forward_fmt() {
+        struct Marker;
+
+        struct Hash(u32);
+        forward_fmt!(Hash => Display, Debug, LowerHex, UpperHex);
+
+        struct Digest(u32);
+        forward_fmt!(Digest => [Display, LowerHex,]);
+
+        struct Float {
+            value: f64,
+        }
+        struct Id<T>(T);
+        struct Tagged<T, M>(T, PhantomData<M>);
+        struct Inner {
+            value: u8,
+        }
+        struct Outer {
+            inner: Inner,
+        }
+        forward_fmt! {
+            Float => [LowerExp, UpperExp] value;
+            <T> in Id<T> => Binary, Octal;
+            <T, M> in Tagged<T, M> => [Debug, Pointer] 0: T;
+            Outer => [Display] inner.value;
+        }
+
+        assert_eq!(format!("{}", Hash(255)), "255");
+        assert_eq!(format!("{:?}", Hash(255)), "255");
+        assert_eq!(format!("{:x}", Hash(255)), "ff");
+        assert_eq!(format!("{:X}", Hash(255)), "FF");
+        assert_eq!(format!("{} {:x}", Digest(255), Digest(255)), "255 ff");
+        assert_eq!(format!("{:e}", Float { value: 1500.0 }), "1.5e3");
+        assert_eq!(format!("{:E}", Float { value: 1500.0 }), "1.5E3");
+        assert_eq!(format!("{:b}", Id(5)), "101");
+        assert_eq!(format!("{:o}", Id(8)), "10");
+        assert_eq!(format!("{}", Outer { inner: Inner { value: 7 } }), "7");
+
+        let byte = 0;
+        let tagged = Tagged(&byte, PhantomData::<Marker>);
+        assert_eq!(format!("{:?}", tagged), "0");
+        assert_eq!(format!("{:p}", tagged), format!("{:p}", &byte));
+    }
+}
@@ -23,37 +23,53 @@
 /// ```
 #[macro_export]
 macro_rules! impl_from {
-    (<$($generic:ident),+> in $from:ty => $this:ty $(,)?) => {
-        impl <$($generic),+> ::core::convert::From<$from> for $this {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_from [$(#[$attr])*] [=>] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $from:ty => $($rest:tt)*) => {
+        $crate::impl_from!(@impl [$(#[$attr])*] [] [$from] [] => $($rest)*);
+    };
+
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$from:ty] [$($where:tt)*] => $this:ty $(,)?) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::From<$from> for $this where $($where)* {
+            #[inline]
             fn from(from: $from) -> Self {
                 Self(from)
             }
         }
     };
 
-    (<$($generic:ident),+> in $from:ty => $this:ty : $field:ident $(,)?) => {
-        impl <$($generic),+> ::core::convert::From<$from> for $this {
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$from:ty] [$($where:tt)*]
+        => $this:ty : $field:ident $(,)?
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::From<$from> for $this where $($where)* {
+            #[inline]
             fn from(from: $from) -> Self {
                 Self { $field: from }
             }
         }
     };
 
-    ($from:ty => $this:ty $(,)?) => {
-        impl ::core::convert::From<$from> for $this {
-            fn from(from: $from) -> Self {
-                Self(from)
-            }
-        }
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!("missing target type; expected `impl_from!(From => Type)`");
     };
 
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!("missing target type; expected `impl_from!(From => Type)`");
+    };
 
-    ($from:ty => $this:ty : $field:ident $(,)?) => {
-        impl ::core::convert::From<$from> for $this {
-            fn from(from: $from) -> Self {
-                Self { $field : from }
-            }
-        }
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(
+            "expected `impl_from!(From => Type)` or `impl_from!(From => Type: field)`"
+        );
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_from $($input)*);
     };
 }
 
@@ -71,13 +87,43 @@ macro_rules! impl_from {
 /// ```
 #[macro_export]
 macro_rules! impl_from_for_primitive {
-    ($from:ty => $this:ty $(,)?) => {
-        impl ::core::convert::From<$from> for $this {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_from_for_primitive [$(#[$attr])*] [=>] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $from:ty => $($rest:tt)*) => {
+        $crate::impl_from_for_primitive!(@impl [$(#[$attr])*] [] [$from] [] => $($rest)*);
+    };
+
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$from:ty] [$($where:tt)*] => $this:ty $(,)?) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::From<$from> for $this where $($where)* {
+            #[inline]
             fn from(from: $from) -> $this {
                 <$this as ::core::convert::From<_>>::from(from.0)
             }
         }
     };
+
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!(
+            "missing primitive type; expected `impl_from_for_primitive!(Type => Primitive)`"
+        );
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!(
+            "missing primitive type; expected `impl_from_for_primitive!(Type => Primitive)`"
+        );
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!("expected `impl_from_for_primitive!(Type => Primitive)`");
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_from_for_primitive $($input)*);
+    };
 }
 
 /// Implement [`From`] and [`Into`] for a newtype struct.
@@ -96,21 +142,68 @@ macro_rules! impl_from_for_primitive {
 /// let foo = bool::from(Checked(false));
 /// assert_eq!(foo, false);
 /// ```
+///
+/// With const generic parameters:
+///
+/// ```
+/// use impl_more::impl_newtype_from_into;
+///
+/// struct Frame<const N: usize>([u8; N]);
+/// impl_newtype_from_into!(<const N: usize> in Frame<N> [<=>] [u8; N]);
+///
+/// let frame = Frame::from([1, 2, 3]);
+/// assert_eq!(<[u8; 3]>::from(frame), [1, 2, 3]);
+/// ```
 #[macro_export]
 macro_rules! impl_newtype_from_into {
-    ($newtype:ty [<=>] $inner:ty $(,)?) => {
-        impl ::core::convert::From<$inner> for $newtype {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_newtype_from_into [$(#[$attr])*] [[<=>]] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $newtype:ty [<=>] $($rest:tt)*) => {
+        $crate::impl_newtype_from_into!(@impl [$(#[$attr])*] [] [$newtype] [] [<=>] $($rest)*);
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$newtype:ty] [$($where:tt)*]
+        [<=>] $inner:ty $(,)?
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::From<$inner> for $newtype where $($where)* {
+            #[inline]
             fn from(from: $inner) -> $newtype {
                 Self(from)
             }
         }
 
-        impl ::core::convert::From<$newtype> for $inner {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::From<$newtype> for $inner where $($where)* {
+            #[inline]
             fn from(from: $newtype) -> $inner {
                 from.0
             }
         }
     };
+
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!(
+            "missing inner type; expected `impl_newtype_from_into!(Type [<=>] Inner)`"
+        );
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!(
+            "missing inner type; expected `impl_newtype_from_into!(Type [<=>] Inner)`"
+        );
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!("expected `impl_newtype_from_into!(Type [<=>] Inner)`");
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_newtype_from_into $($input)*);
+    };
 }
 
 /// Implement [`Into`] for a struct.
@@ -141,36 +234,53 @@ macro_rules! impl_newtype_from_into {
 /// ```
 #[macro_export]
 macro_rules! impl_into {
-    (<$($generic:ident),+> in $this:ty => $inner:ty : $field:ident) => {
-        impl <$($generic),+> ::core::convert::Into<$inner> for $this {
-            fn into(self) -> $inner {
-                self.$field
-            }
-        }
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_into [$(#[$attr])*] [=>] <$($rest)*);
     };
 
-    (<$($generic:ident),+> in $this:ty => $inner:ty) => {
-        impl <$($generic),+> ::core::convert::Into<$inner> for $this {
+    ($(#[$attr:meta])* $this:ty => $($rest:tt)*) => {
+        $crate::impl_into!(@impl [$(#[$attr])*] [] [$this] [] => $($rest)*);
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*]
+        => $inner:ty : $($field:tt).+
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::Into<$inner> for $this where $($where)* {
+            #[inline]
             fn into(self) -> $inner {
-                self.0
+                self.$($field).+
             }
         }
     };
 
-    ($this:ty => $inner:ty) => {
-        impl ::core::convert::Into<$inner> for $this {
+    (@impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*] => $inner:ty) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::convert::Into<$inner> for $this where $($where)* {
+            #[inline]
             fn into(self) -> $inner {
                 self.0
             }
         }
     };
 
-    ($this:ty => $inner:ty : $field:ident) => {
-        impl ::core::convert::Into<$inner> for $this {
-            fn into(self) -> $inner {
-                self.$field
-            }
-        }
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!("missing target type; expected `impl_into!(Type => Target)`");
+    };
+
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!("missing target type; expected `impl_into!(Type => Target)`");
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(
+            "expected `impl_into!(Type => Target)` or `impl_into!(Type => Target: field)`"
+        );
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_into $($input)*);
     };
 }
 
@@ -178,7 +288,7 @@ macro_rules! impl_into {
 mod tests {
     #![allow(clippy::from_over_into)]
 
-    use alloc::rc::Rc;
+    use alloc::{rc::Rc, vec, vec::Vec};
 
     #[test]
     fn newtype() {
@@ -215,6 +325,66 @@ mod tests {
         assert_eq!(*foo.0, 42);
     }
 
+    #[test]
+    fn newtype_generic_bounds() {
+        struct Foo<T>(Vec<T>);
+        impl_from!(<T> in Vec<T> where T: Copy => Foo<T>);
+        impl_into!(<T: Copy> in Foo<T> => Vec<T>);
+
+        struct Bar<T>(Vec<T>);
+        impl_newtype_from_into!(<T> in Bar<T> where T: Copy [<=>] Vec<T>);
+
+        static_assertions::assert_impl_all!(Foo<u8>: From<Vec<u8>>, Into<Vec<u8>>);
+        static_assertions::assert_not_impl_any!(Foo<Rc<u8>>: From<Vec<Rc<u8>>>);
+        static_assertions::assert_impl_all!(Bar<u8>: From<Vec<u8>>, Into<Vec<u8>>);
+
+        let foo = Foo::from(vec![4, 2]);
+        assert_eq!(foo.0, [4, 2]);
+    }
+
+    #[test]
+    fn newtype_borrowed() {
+        struct Name<'a>(&'a str);
+        impl_from!(<'a> in &'a str => Name<'a>);
+        impl_into!(<'a> in Name<'a> => &'a str);
+
+        struct View<'a, T>(&'a [T]);
+        impl_newtype_from_into!(<'a, T> in View<'a, T> [<=>] &'a [T]);
+
+        static_assertions::assert_impl_all!(Name<'static>: From<&'static str>, Into<&'static str>);
+        static_assertions::assert_impl_all!(
+            View<'static, u8>:
+            From<&'static [u8]>, Into<&'static [u8]>
+        );
+
+        let name = Name::from("foo");
+        assert_eq!(name.0, "foo");
+
+        let items = [1, 2, 3];
+        let view = View::from(&items[..]);
+        let items: &[u8] = view.into();
+        assert_eq!(items, [1, 2, 3]);
+    }
+
+    #[test]
+    fn newtype_const_generic() {
+        struct Frame<const N: usize>([u8; N]);
+        impl_from!(<const N: usize> in [u8; N] => Frame<N>);
+        impl_into!(<const N: usize> in Frame<N> => [u8; N]);
+
+        struct Packet<T, const N: usize>([T; N]);
+        impl_newtype_from_into!(<T, const N: usize> in Packet<T, N> [<=>] [T; N]);
+
+        static_assertions::assert_impl_all!(Frame<4>: From<[u8; 4]>, Into<[u8; 4]>);
+        static_assertions::assert_impl_all!(Packet<u16, 2>: From<[u16; 2]>, Into<[u16; 2]>);
+
+        let frame = Frame::from([1, 2, 3]);
+        assert_eq!(frame.0, [1, 2, 3]);
+
+        let packet = Packet::from([4_u16, 2]);
+        assert_eq!(<[u16; 2]>::from(packet), [4, 2]);
+    }
+
     #[test]
     fn named_field() {
         struct Foo {
@@ -242,6 +412,32 @@ mod tests {
         assert_eq!(foo.big, 42);
     }
 
+    #[test]
+    fn field_path() {
+        struct Tagged<M>(core::marker::PhantomData<M>, usize);
+        impl_into!(<M> in Tagged<M> => usize : 1);
+
+        struct Inner {
+            value: u64,
+        }
+        struct Outer {
+            inner: Inner,
+        }
+        impl_into!(Outer => u64 : inner.value);
+
+        static_assertions::assert_impl_all!(Tagged<()>: Into<usize>);
+        static_assertions::assert_impl_all!(Outer: Into<u64>);
+
+        let value: usize = Tagged::<()>(core::marker::PhantomData, 42).into();
+        assert_eq!(value, 42);
+
+        let value: u64 = Outer {
+            inner: Inner { value: 42 },
+        }
+        .into();
+        assert_eq!(value, 42);
+    }
+
     #[test]
     fn named_field_generic() {
         struct Foo<T> {
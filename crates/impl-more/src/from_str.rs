@@ -29,26 +29,46 @@
 /// [`FromStr`]: core::str::FromStr
 #[macro_export]
 macro_rules! forward_from_str {
-    (<$($generic:ident),+> in $this:ty => $inner:ty $(,)?) => {
-        impl <$($generic),+> ::core::str::FromStr for $this
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(forward_from_str [$(#[$attr])*] [=>] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $this:ty => $($rest:tt)*) => {
+        $crate::forward_from_str!(@impl [$(#[$attr])*] [] [$this] [] => $($rest)*);
+    };
+
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*]
+        => $inner:ty $(,)?
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::str::FromStr for $this
         where
             $inner: ::core::str::FromStr,
+            $($where)*
         {
             type Err = <$inner as ::core::str::FromStr>::Err;
 
+            #[inline]
             fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
                 <$inner as ::core::str::FromStr>::from_str(value).map(Self)
             }
         }
     };
 
-    (<$($generic:ident),+> in $this:ty => $field:ident : $inner:ty $(,)?) => {
-        impl <$($generic),+> ::core::str::FromStr for $this
+    (
+        @impl [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*]
+        => $field:ident : $inner:ty $(,)?
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::str::FromStr for $this
         where
             $inner: ::core::str::FromStr,
+            $($where)*
         {
             type Err = <$inner as ::core::str::FromStr>::Err;
 
+            #[inline]
             fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
                 <$inner as ::core::str::FromStr>::from_str(value)
                     .map(|$field| Self { $field })
@@ -56,25 +76,23 @@ macro_rules! forward_from_str {
         }
     };
 
-    ($this:ty => $inner:ty $(,)?) => {
-        impl ::core::str::FromStr for $this {
-            type Err = <$inner as ::core::str::FromStr>::Err;
+    (@invalid $(#[$attr:meta])* $this:ty) => {
+        ::core::compile_error!("missing inner type; expected `forward_from_str!(Type => Inner)`");
+    };
 
-            fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
-                <$inner as ::core::str::FromStr>::from_str(value).map(Self)
-            }
-        }
+    (@invalid @impl $ctx:tt $generics:tt $this:tt $where:tt) => {
+        ::core::compile_error!("missing inner type; expected `forward_from_str!(Type => Inner)`");
     };
 
-    ($this:ty => $field:ident : $inner:ty $(,)?) => {
-        impl ::core::str::FromStr for $this {
-            type Err = <$inner as ::core::str::FromStr>::Err;
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected `forward_from_str!(Type => Inner)` or ",
+            "`forward_from_str!(Type => field: Inner)`",
+        ));
+    };
 
-            fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
-                <$inner as ::core::str::FromStr>::from_str(value)
-                    .map(|$field| Self { $field })
-            }
-        }
+    ($($input:tt)*) => {
+        $crate::__batch!(forward_from_str $($input)*);
     };
 }
 
@@ -102,6 +120,14 @@ mod tests {
     }
     forward_from_str!(<T> in GenericNamed<T> => inner: T);
 
+    #[derive(Debug, PartialEq)]
+    struct Bounded<T: Copy>(T);
+    forward_from_str!(<T> in Bounded<T> where T: Copy => T);
+
+    #[derive(Debug, PartialEq)]
+    struct Scaled<const N: u32>(u32);
+    forward_from_str!(<const N: u32> in Scaled<N> => u32);
+
     static_assertions::assert_impl_all!(Newtype: core::str::FromStr<Err = ParseIntError>);
     static_assertions::assert_impl_all!(Named: core::str::FromStr<Err = core::str::ParseBoolError>);
     static_assertions::assert_impl_all!(Generic<u16>: core::str::FromStr<Err = ParseIntError>);
@@ -126,6 +152,16 @@ mod tests {
         assert_eq!(Generic::<u16>::from_str("8080"), Ok(Generic(8080)));
     }
 
+    #[test]
+    fn forwards_generic_bounded_newtype() {
+        assert_eq!(Bounded::<u16>::from_str("8080"), Ok(Bounded(8080)));
+    }
+
+    #[test]
+    fn forwards_const_generic_newtype() {
+        assert_eq!(Scaled::<10>::from_str("42"), Ok(Scaled(42)));
+    }
+
     #[test]
     fn forwards_generic_named_field() {
         assert_eq!(
@@ -0,0 +1,224 @@
+/// Parses the generics header shared by this crate's macros.
+///
+/// The header has the form `<$params> in $ty` and may be followed by a `where` clause. Generic
+/// parameters are passed through verbatim, so they may contain lifetimes, bounds, and const
+/// parameters.
+///
+/// Invoked as `__parse_generics!(callback [ctx] [sep] <...> in Ty where ... rest)`, where `sep` is
+/// the delimiter the calling macro expects after the type (`=>`, `:`, or `[<=>]`). The callback
+/// macro is then invoked as `callback!(@impl [ctx] [params] [Ty] [where-predicates] rest)`.
+///
+/// The `[()]` delimiter instead expects the type to be followed by a parenthesized field type and a
+/// `:`, as in `Ty(Inner): ...`, and does not accept a `where` clause.
+///
+/// With the `:` delimiter, the type may instead be followed by a destructuring pattern, as in
+/// `Ty { a, b }: ...` or `Ty(a, b): ...`, which is passed to the callback after the where clause.
+/// A `where` clause goes between the type and the pattern, as in `Ty where T: Bound { a }: ...`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parse_generics {
+    ($cb:ident $ctx:tt $sep:tt < $($rest:tt)*) => {
+        $crate::__parse_generics!(@params $cb $ctx $sep [] [] $($rest)*);
+    };
+
+    // munch generic parameters, tracking angle bracket depth
+    (@params $cb:ident $ctx:tt $sep:tt [$($params:tt)*] [$($depth:tt)*] < $($rest:tt)*) => {
+        $crate::__parse_generics!(@params $cb $ctx $sep [$($params)* <] [@ $($depth)*] $($rest)*);
+    };
+
+    (@params $cb:ident $ctx:tt $sep:tt [$($params:tt)*] [] > $($rest:tt)*) => {
+        $crate::__parse_generics!(@ty $cb $ctx $sep [$($params)*] $($rest)*);
+    };
+
+    (@params $cb:ident $ctx:tt $sep:tt [$($params:tt)*] [@ $($depth:tt)*] > $($rest:tt)*) => {
+        $crate::__parse_generics!(@params $cb $ctx $sep [$($params)* >] [$($depth)*] $($rest)*);
+    };
+
+    (@params $cb:ident $ctx:tt $sep:tt [$($params:tt)*] [@] >> $($rest:tt)*) => {
+        $crate::__parse_generics!(@ty $cb $ctx $sep [$($params)* >] $($rest)*);
+    };
+
+    (@params $cb:ident $ctx:tt $sep:tt [$($params:tt)*] [@ @ $($depth:tt)*] >> $($rest:tt)*) => {
+        $crate::__parse_generics!(@params $cb $ctx $sep [$($params)* >>] [$($depth)*] $($rest)*);
+    };
+
+    (@params $cb:ident $ctx:tt $sep:tt [$($params:tt)*] $depth:tt $next:tt $($rest:tt)*) => {
+        $crate::__parse_generics!(@params $cb $ctx $sep [$($params)* $next] $depth $($rest)*);
+    };
+
+    (@params $cb:ident $ctx:tt $sep:tt $params:tt $depth:tt) => {
+        ::core::compile_error!("unclosed generic parameter list; expected `<...> in Type`");
+    };
+
+    // self type followed by a parenthesized field type
+    (@ty $cb:ident $ctx:tt [()] $params:tt in $($rest:tt)*) => {
+        $crate::__parse_generics!(@fields $cb $ctx $params [] $($rest)*);
+    };
+
+    (@fields $cb:ident $ctx:tt $params:tt [$($this:tt)*] ($($inner:tt)*) : $($rest:tt)*) => {
+        $crate::$cb!(@impl $ctx $params [$($this)*] [] ($($inner)*) : $($rest)*);
+    };
+
+    (@fields $cb:ident $ctx:tt $params:tt [$($this:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__parse_generics!(@fields $cb $ctx $params [$($this)* $next] $($rest)*);
+    };
+
+    (@fields $cb:ident $ctx:tt $params:tt $this:tt) => {
+        ::core::compile_error!("expected `in Type(Inner): ...` after generic parameters");
+    };
+
+    // self type, optionally followed by a where clause
+    (@ty $cb:ident $ctx:tt $sep:tt $params:tt in $this:ty where $($rest:tt)*) => {
+        $crate::__parse_generics!(@where $cb $ctx $sep $params [$this] [] $($rest)*);
+    };
+
+    (@ty $cb:ident $ctx:tt $sep:tt $params:tt in $this:ty => $($rest:tt)*) => {
+        $crate::$cb!(@impl $ctx $params [$this] [] => $($rest)*);
+    };
+
+    (@ty $cb:ident $ctx:tt $sep:tt $params:tt in $this:ty : $($rest:tt)*) => {
+        $crate::$cb!(@impl $ctx $params [$this] [] : $($rest)*);
+    };
+
+    (@ty $cb:ident $ctx:tt $sep:tt $params:tt in $this:ty [<=>] $($rest:tt)*) => {
+        $crate::$cb!(@impl $ctx $params [$this] [] [<=>] $($rest)*);
+    };
+
+    (@ty $cb:ident $ctx:tt $sep:tt $params:tt in $this:ty) => {
+        $crate::$cb!(@impl $ctx $params [$this] []);
+    };
+
+    // self type followed by a destructuring pattern
+    (@ty $cb:ident $ctx:tt [:] $params:tt in $($rest:tt)*) => {
+        $crate::__parse_generics!(@pattern $cb $ctx $params [] $($rest)*);
+    };
+
+    (@ty $cb:ident $ctx:tt $sep:tt $params:tt $($rest:tt)*) => {
+        ::core::compile_error!("expected `in Type` after generic parameters");
+    };
+
+    (@pattern $cb:ident $ctx:tt $params:tt [$($this:tt)*] ($($fields:tt)*) : $($rest:tt)*) => {
+        $crate::$cb!(@impl $ctx $params [$($this)*] [] ($($fields)*) : $($rest)*);
+    };
+
+    (@pattern $cb:ident $ctx:tt $params:tt [$($this:tt)*] {$($fields:tt)*} : $($rest:tt)*) => {
+        $crate::$cb!(@impl $ctx $params [$($this)*] [] {$($fields)*} : $($rest)*);
+    };
+
+    (@pattern $cb:ident $ctx:tt $params:tt [$($this:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__parse_generics!(@pattern $cb $ctx $params [$($this)* $next] $($rest)*);
+    };
+
+    (@pattern $cb:ident $ctx:tt $params:tt $this:tt) => {
+        ::core::compile_error!("expected `in Type` after generic parameters");
+    };
+
+    // munch where clause predicates until the calling macro's delimiter
+    (@where $cb:ident $ctx:tt [=>] $params:tt $this:tt $where:tt => $($rest:tt)*) => {
+        $crate::$cb!(@impl $ctx $params $this $where => $($rest)*);
+    };
+
+    (@where $cb:ident $ctx:tt [[<=>]] $params:tt $this:tt $where:tt [<=>] $($rest:tt)*) => {
+        $crate::$cb!(@impl $ctx $params $this $where [<=>] $($rest)*);
+    };
+
+    (@where $cb:ident $ctx:tt [:] $params:tt $this:tt $where:tt : $format:literal $($rest:tt)*) => {
+        $crate::$cb!(@impl $ctx $params $this $where : $format $($rest)*);
+    };
+
+    (
+        @where $cb:ident $ctx:tt [:] $params:tt $this:tt $where:tt
+        : case = $case:ident , $($rest:tt)*
+    ) => {
+        $crate::$cb!(@impl $ctx $params $this $where : case = $case , $($rest)*);
+    };
+
+    (
+        @where $cb:ident $ctx:tt [:] $params:tt $this:tt $where:tt
+        : case = $case:ident ; $($rest:tt)*
+    ) => {
+        $crate::$cb!(@impl $ctx $params $this $where : case = $case ; $($rest)*);
+    };
+
+    // a parenthesized group is a tuple type when it starts a predicate, or the arguments of an
+    // `Fn` bound, and a destructuring pattern otherwise
+    (@where $cb:ident $ctx:tt [:] $params:tt $this:tt [] ($($tuple:tt)*) $($rest:tt)*) => {
+        $crate::__parse_generics!(@where $cb $ctx [:] $params $this [($($tuple)*)] $($rest)*);
+    };
+
+    (
+        @where $cb:ident $ctx:tt [:] $params:tt $this:tt [$($where:tt)*]
+        , ($($tuple:tt)*) $($rest:tt)*
+    ) => {
+        $crate::__parse_generics!(
+            @where $cb $ctx [:] $params $this [$($where)* , ($($tuple)*)] $($rest)*
+        );
+    };
+
+    (
+        @where $cb:ident $ctx:tt [:] $params:tt $this:tt [$($where:tt)*]
+        Fn ($($args:tt)*) $($rest:tt)*
+    ) => {
+        $crate::__parse_generics!(
+            @where $cb $ctx [:] $params $this [$($where)* Fn ($($args)*)] $($rest)*
+        );
+    };
+
+    (
+        @where $cb:ident $ctx:tt [:] $params:tt $this:tt [$($where:tt)*]
+        FnMut ($($args:tt)*) $($rest:tt)*
+    ) => {
+        $crate::__parse_generics!(
+            @where $cb $ctx [:] $params $this [$($where)* FnMut ($($args)*)] $($rest)*
+        );
+    };
+
+    (
+        @where $cb:ident $ctx:tt [:] $params:tt $this:tt [$($where:tt)*]
+        FnOnce ($($args:tt)*) $($rest:tt)*
+    ) => {
+        $crate::__parse_generics!(
+            @where $cb $ctx [:] $params $this [$($where)* FnOnce ($($args)*)] $($rest)*
+        );
+    };
+
+    (@where $cb:ident $ctx:tt [:] $params:tt $this:tt $where:tt ($($fields:tt)*) : $($rest:tt)*) => {
+        $crate::$cb!(@impl $ctx $params $this $where ($($fields)*) : $($rest)*);
+    };
+
+    (@where $cb:ident $ctx:tt [:] $params:tt $this:tt $where:tt {$($fields:tt)*} : $($rest:tt)*) => {
+        $crate::$cb!(@impl $ctx $params $this $where {$($fields)*} : $($rest)*);
+    };
+
+    (
+        @where $cb:ident $ctx:tt [:] $params:tt $this:tt $where:tt
+        : $variant:ident => $($rest:tt)*
+    ) => {
+        $crate::$cb!(@impl $ctx $params $this $where : $variant => $($rest)*);
+    };
+
+    (
+        @where $cb:ident $ctx:tt [:] $params:tt $this:tt $where:tt
+        : $variant:ident ($($fields:tt)*) => $($rest:tt)*
+    ) => {
+        $crate::$cb!(@impl $ctx $params $this $where : $variant ($($fields)*) => $($rest)*);
+    };
+
+    (
+        @where $cb:ident $ctx:tt [:] $params:tt $this:tt $where:tt
+        : $variant:ident {$($fields:tt)*} => $($rest:tt)*
+    ) => {
+        $crate::$cb!(@impl $ctx $params $this $where : $variant {$($fields)*} => $($rest)*);
+    };
+
+    (@where $cb:ident $ctx:tt $sep:tt $params:tt $this:tt $where:tt) => {
+        $crate::$cb!(@impl $ctx $params $this $where);
+    };
+
+    (
+        @where $cb:ident $ctx:tt $sep:tt $params:tt $this:tt [$($where:tt)*]
+        $next:tt $($rest:tt)*
+    ) => {
+        $crate::__parse_generics!(@where $cb $ctx $sep $params $this [$($where)* $next] $($rest)*);
+    };
+}
@@ -4,6 +4,84 @@
 //!
 //! Where possible, these macros emit `#[no_std]`-compatible code.
 //!
+//! # Generics
+//!
+//! Every macro accepts a generics header of the form `<$params> in $type`, optionally followed by
+//! a `where` clause. The parameters are used verbatim in the emitted impl, so they can contain
+//! lifetimes, trait bounds, and const generics.
+//!
+//! The only exceptions are `forward_display!`, the other `core::fmt` forwarding macros (including
+//! `forward_fmt!`), and `forward_error!`: when every parameter is a plain type parameter without
+//! bounds and no `where` clause is given, each parameter is bounded on the forwarded trait
+//! (`Error + 'static` for `forward_error!`). Giving the forwarded field's type bounds that type
+//! instead, and declaring any bound or `where` clause turns off the implicit bounds. No other macro
+//! adds bounds, so marker parameters are left unconstrained.
+//!
+//! ```
+//! use std::fmt;
+//!
+//! struct Labelled<T: fmt::Display> {
+//!     label: T,
+//! }
+//!
+//! impl_more::impl_as_ref!(<T: fmt::Display> in Labelled<T> => label: T);
+//! impl_more::forward_display!(<T> in Labelled<T> where T: fmt::Display => label);
+//!
+//! assert_eq!(Labelled { label: 42 }.to_string(), "42");
+//! ```
+//!
+//! # Attributes
+//!
+//! Every macro accepts a leading list of attributes which are applied to the emitted impl blocks,
+//! for example, to gate them behind a feature flag. Trivial forwarding methods are marked
+//! `#[inline]`.
+//!
+//! ```
+//! struct Id(u64);
+//!
+//! impl_more::forward_display!(#[cfg(feature = "display")] #[automatically_derived] Id);
+//! impl_more::impl_from!(#[doc = "Wraps a raw ID."] u64 => Id);
+//! ```
+//!
+//! # Batches
+//!
+//! Every macro accepts a `;`-separated batch of inputs, each expanding as though the macro was
+//! invoked once for it. The [`impls!`] macro goes further and groups invocations of several macros
+//! into a single block.
+//!
+//! ```
+//! struct UserId(String);
+//! struct OrgId(String);
+//! struct Seq(u64);
+//!
+//! impl_more::impl_from! { String => UserId; String => OrgId; u64 => Seq }
+//!
+//! impl_more::impls! {
+//!     impl_as_ref! { UserId => String; OrgId => String }
+//!     forward_display! { UserId; OrgId; Seq }
+//! }
+//!
+//! assert_eq!(Seq::from(7).to_string(), "7");
+//! ```
+//!
+//! # Newtypes
+//!
+//! Commonly combined impls can be selected by name with [`impl_newtype!`], and [`newtype!`] also
+//! declares the struct itself.
+//!
+//! ```
+//! struct UserId(String);
+//! impl_more::impl_newtype!(UserId(String): From, Into, AsRef<str>, Deref, Display, FromStr);
+//!
+//! impl_more::newtype! {
+//!     #[derive(Debug, Clone)]
+//!     pub struct Email(String) impl { Deref, Display, FromStr, From }
+//! }
+//!
+//! let email: Email = "hi@example.com".parse().unwrap();
+//! assert!(email.ends_with("example.com"));
+//! ```
+//!
 //! # Usage
 //!
 //! ```
@@ -93,6 +171,10 @@ extern crate alloc;
 #[cfg(test)]
 extern crate std;
 
+#[macro_use]
+mod batch;
+#[macro_use]
+mod generics;
 #[macro_use]
 mod as_ref;
 #[macro_use]
@@ -102,9 +184,21 @@ mod display;
 #[macro_use]
 mod error;
 #[macro_use]
+mod fmt;
+#[macro_use]
 mod from;
 #[macro_use]
 mod from_str;
+#[macro_use]
+mod newtype;
+mod case;
+mod pad;
+
+#[doc(hidden)]
+pub use crate::{
+    case::{Case as __Case, Cased as __Cased},
+    pad::pad_args as __pad_args,
+};
 
 #[cfg(test)]
 mod tests {
@@ -151,6 +245,33 @@ mod tests {
         DerefMut,
     );
 
+    #[derive(Debug)]
+    struct Gated(String);
+
+    crate::impl_as_ref!(#[cfg(all())] Gated => String);
+    crate::impl_deref!(#[cfg(any())] Gated => String);
+    crate::impl_from!(#[cfg(any())] <T: Into<String>> in T => Gated);
+    crate::forward_display!(
+        #[automatically_derived]
+        #[allow(unused_qualifications)]
+        Gated
+    );
+    crate::impl_as_mut!(#[doc = "Mutable access to the inner string."] Gated => String);
+
+    static_assertions::assert_impl_all!(
+        Gated:
+        // impls
+        AsRef<String>,
+        AsMut<String>,
+        core::fmt::Display,
+    );
+    static_assertions::assert_not_impl_any!(
+        Gated:
+        // impls
+        Deref,
+        From<String>,
+    );
+
     #[allow(dead_code)]
     #[test]
     fn impl_display() {
@@ -180,13 +301,19 @@ mod tests {
         crate::impl_display_enum!(FooContents2: Qux { msg } => "msg: {msg}");
         assert_eq!(FooContents2::Qux { msg: "foo" }.to_string(), "msg: foo");
 
-        // not supported yet
-        // enum FooContents3 {
-        //     Bar(u64, u64),
-        //     Qux { msg: &'static str },
-        // }
-        // impl_display_enum!(FooContents3, Bar (x, y) => "x: {x}; y: {y}", Qux { msg } => "{msg}");
-        // assert_eq!(FooContents3::Bar(4, 2).to_string(), "x: 4; y: 2");
-        // assert_eq!(FooContents3::Qux { msg: "foo" }.to_string(), "x: 4; y: 2");
+        enum FooContents3 {
+            Bar(u64, u64),
+            Qux { msg: &'static str },
+            Baz,
+        }
+        crate::impl_display_enum!(
+            FooContents3:
+            Bar (x, y) => "x: {x}; y: {y}",
+            Qux { msg } => "{msg}",
+            Baz => "baz",
+        );
+        assert_eq!(FooContents3::Bar(4, 2).to_string(), "x: 4; y: 2");
+        assert_eq!(FooContents3::Qux { msg: "foo" }.to_string(), "foo");
+        assert_eq!(FooContents3::Baz.to_string(), "baz");
     }
 }
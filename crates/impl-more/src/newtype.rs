@@ -0,0 +1,397 @@
+/// Implement a selection of traits for a newtype struct.
+///
+/// The struct and its field type are followed by a comma-separated list of traits, each of which is
+/// implemented using the corresponding macro from this crate:
+///
+/// | Trait        | Implemented using                                    |
+/// |--------------|------------------------------------------------------|
+/// | `From`       | [`impl_from!`]                                       |
+/// | `Into`       | [`impl_into!`]                                       |
+/// | `AsRef`      | [`impl_as_ref!`], targeting the field type           |
+/// | `AsRef<T>`   | [`forward_as_ref!`]                                  |
+/// | `AsMut`      | [`impl_as_mut!`], targeting the field type           |
+/// | `AsMut<T>`   | [`forward_as_mut!`]                                  |
+/// | `Borrow`     | returns the field                                    |
+/// | `Borrow<T>`  | forwards to the field's [`Borrow`] implementation    |
+/// | `Deref`      | [`impl_deref!`]                                      |
+/// | `DerefMut`   | [`impl_deref_mut!`]                                  |
+/// | `Display`    | [`forward_display!`]                                 |
+/// | `FromStr`    | [`forward_from_str!`]                                |
+///
+/// Forwarding impls are bounded on the field type implementing the forwarded trait, so they can be
+/// used with generic newtypes.
+///
+/// # Examples
+/// ```
+/// use std::borrow::Borrow;
+/// use impl_more::impl_newtype;
+///
+/// struct UserId(String);
+/// impl_newtype!(UserId(String): From, Into, AsRef<str>, Deref, Display, FromStr, Borrow<str>);
+///
+/// let id: UserId = "alice".parse().unwrap();
+/// assert_eq!(id.len(), 5);
+/// assert_eq!(id.to_string(), "alice");
+/// assert_eq!(Borrow::<str>::borrow(&id), "alice");
+/// ```
+///
+/// With type parameters:
+/// ```
+/// use impl_more::impl_newtype;
+///
+/// struct Tagged<T>(T);
+/// impl_newtype!(<T> in Tagged<T>(T): From, AsRef, Display);
+///
+/// assert_eq!(Tagged::from(42).to_string(), "42");
+/// ```
+///
+/// [`Borrow`]: core::borrow::Borrow
+/// [`impl_from!`]: crate::impl_from
+/// [`impl_into!`]: crate::impl_into
+/// [`impl_as_ref!`]: crate::impl_as_ref
+/// [`forward_as_ref!`]: crate::forward_as_ref
+/// [`impl_as_mut!`]: crate::impl_as_mut
+/// [`forward_as_mut!`]: crate::forward_as_mut
+/// [`impl_deref!`]: crate::impl_deref
+/// [`impl_deref_mut!`]: crate::impl_deref_mut
+/// [`forward_display!`]: crate::forward_display
+/// [`forward_from_str!`]: crate::forward_from_str
+#[macro_export]
+macro_rules! impl_newtype {
+    ($(#[$attr:meta])* <$($rest:tt)*) => {
+        $crate::__parse_generics!(impl_newtype [$(#[$attr])*] [()] <$($rest)*);
+    };
+
+    ($(#[$attr:meta])* $this:ident ($inner:ty) : $($traits:tt)*) => {
+        $crate::impl_newtype!(@impl [$(#[$attr])*] [] [$this] [] ($inner) : $($traits)*);
+    };
+
+    (@impl $ctx:tt $generics:tt $this:tt $where:tt ($inner:ty) : $($traits:tt)*) => {
+        $crate::impl_newtype!(@trait $ctx $generics $this $where [$inner] $($traits)*);
+    };
+
+    (@trait $ctx:tt $generics:tt $this:tt $where:tt $inner:tt) => {};
+
+    (
+        @trait $ctx:tt $generics:tt [$($this:tt)*] $where:tt [$inner:ty]
+        From $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_from!(@impl $ctx $generics [$inner] $where => $($this)*);
+        $crate::impl_newtype!(@trait $ctx $generics [$($this)*] $where [$inner] $($($rest)*)?);
+    };
+
+    (
+        @trait $ctx:tt $generics:tt $this:tt $where:tt [$inner:ty]
+        Into $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_into!(@impl $ctx $generics $this $where => $inner);
+        $crate::impl_newtype!(@trait $ctx $generics $this $where [$inner] $($($rest)*)?);
+    };
+
+    (
+        @trait $ctx:tt $generics:tt $this:tt [$($where:tt)*] [$inner:ty]
+        AsRef < $target:ty > $(, $($rest:tt)*)?
+    ) => {
+        $crate::forward_as_ref!(
+            @impl $ctx $generics $this
+            [$inner: ::core::convert::AsRef<$target>, $($where)*] => $target
+        );
+        $crate::impl_newtype!(@trait $ctx $generics $this [$($where)*] [$inner] $($($rest)*)?);
+    };
+
+    (
+        @trait $ctx:tt $generics:tt $this:tt $where:tt [$inner:ty]
+        AsRef $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_as_ref!(@impl $ctx $generics $this $where => $inner);
+        $crate::impl_newtype!(@trait $ctx $generics $this $where [$inner] $($($rest)*)?);
+    };
+
+    (
+        @trait $ctx:tt $generics:tt $this:tt [$($where:tt)*] [$inner:ty]
+        AsMut < $target:ty > $(, $($rest:tt)*)?
+    ) => {
+        $crate::forward_as_mut!(
+            @impl $ctx $generics $this
+            [$inner: ::core::convert::AsMut<$target>, $($where)*] => $target
+        );
+        $crate::impl_newtype!(@trait $ctx $generics $this [$($where)*] [$inner] $($($rest)*)?);
+    };
+
+    (
+        @trait $ctx:tt $generics:tt $this:tt $where:tt [$inner:ty]
+        AsMut $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_as_mut!(@impl $ctx $generics $this $where => $inner);
+        $crate::impl_newtype!(@trait $ctx $generics $this $where [$inner] $($($rest)*)?);
+    };
+
+    (
+        @trait [$(#[$attr:meta])*] [$($generics:tt)*] [$this:ty] [$($where:tt)*] [$inner:ty]
+        Borrow < $target:ty > $(, $($rest:tt)*)?
+    ) => {
+        $(#[$attr])*
+        impl <$($generics)*> ::core::borrow::Borrow<$target> for $this
+        where
+            $inner: ::core::borrow::Borrow<$target>,
+            $($where)*
+        {
+            #[inline]
+            fn borrow(&self) -> &$target {
+                ::core::borrow::Borrow::<$target>::borrow(&self.0)
+            }
+        }
+
+        $crate::impl_newtype!(
+            @trait [$(#[$attr])*] [$($generics)*] [$this] [$($where)*] [$inner]
+            $($($rest)*)?
+        );
+    };
+
+    (
+        @trait $ctx:tt $generics:tt $this:tt $where:tt [$inner:ty]
+        Borrow $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_newtype!(
+            @trait $ctx $generics $this $where [$inner] Borrow<$inner> $(, $($rest)*)?
+        );
+    };
+
+    (
+        @trait $ctx:tt $generics:tt $this:tt $where:tt [$inner:ty]
+        Deref $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_deref!(@impl $ctx $generics $this $where => $inner);
+        $crate::impl_newtype!(@trait $ctx $generics $this $where [$inner] $($($rest)*)?);
+    };
+
+    (
+        @trait $ctx:tt $generics:tt $this:tt $where:tt [$inner:ty]
+        DerefMut $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_deref_mut!(@impl $ctx $generics $this $where);
+        $crate::impl_newtype!(@trait $ctx $generics $this $where [$inner] $($($rest)*)?);
+    };
+
+    (
+        @trait $ctx:tt $generics:tt $this:tt [$($where:tt)*] [$inner:ty]
+        Display $(, $($rest:tt)*)?
+    ) => {
+        $crate::forward_display!(
+            @impl $ctx $generics $this [$inner: ::core::fmt::Display, $($where)*]
+        );
+        $crate::impl_newtype!(@trait $ctx $generics $this [$($where)*] [$inner] $($($rest)*)?);
+    };
+
+    (
+        @trait $ctx:tt $generics:tt $this:tt $where:tt [$inner:ty]
+        FromStr $(, $($rest:tt)*)?
+    ) => {
+        $crate::forward_from_str!(@impl $ctx $generics $this $where => $inner);
+        $crate::impl_newtype!(@trait $ctx $generics $this $where [$inner] $($($rest)*)?);
+    };
+
+    (@invalid $($input:tt)*) => {
+        ::core::compile_error!("expected `impl_newtype!(Type(Inner): Trait, ...)`");
+    };
+
+    (@trait $ctx:tt $generics:tt $this:tt $where:tt $inner:tt $trait:tt $(, $($rest:tt)*)?) => {
+        ::core::compile_error!(::core::concat!(
+            "unsupported trait `",
+            ::core::stringify!($trait),
+            "` in `impl_newtype!`; expected one of `From`, `Into`, `AsRef`, `AsMut`, `Borrow`, ",
+            "`Deref`, `DerefMut`, `Display`, or `FromStr`",
+        ));
+    };
+
+    ($($input:tt)*) => {
+        $crate::__batch!(impl_newtype $($input)*);
+    };
+}
+
+/// Declare newtype structs along with a selection of trait impls.
+///
+/// Each declaration is a single-field tuple struct, optionally followed by an `impl { ... }` block
+/// listing the traits to implement. The traits accepted are the same as for [`impl_newtype!`].
+/// Attributes, such as derives, are applied to the struct definition.
+///
+/// # Examples
+/// ```
+/// impl_more::newtype! {
+///     #[derive(Debug, Clone)]
+///     pub struct Email(String) impl { Deref, Display, FromStr, From }
+///
+///     /// Number of attempts made.
+///     pub(crate) struct Attempts(pub u32) impl { From, Into }
+/// }
+///
+/// let email: Email = "hi@example.com".parse().unwrap();
+/// assert!(email.ends_with("example.com"));
+/// let attempts: u32 = Attempts(3).into();
+/// assert_eq!(attempts, 3);
+/// ```
+///
+/// [`impl_newtype!`]: crate::impl_newtype
+#[macro_export]
+macro_rules! newtype {
+    () => {};
+
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $this:ident ($field_vis:vis $inner:ty) impl { $($traits:tt)* }
+        $($rest:tt)*
+    ) => {
+        $crate::newtype!(@struct [$(#[$attr])*] $vis $this ($field_vis $inner));
+        $crate::impl_newtype!($this($inner): $($traits)*);
+        $crate::newtype!($($rest)*);
+    };
+
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $this:ident ($field_vis:vis $inner:ty) ; $($rest:tt)*
+    ) => {
+        $crate::newtype!(@struct [$(#[$attr])*] $vis $this ($field_vis $inner));
+        $crate::newtype!($($rest)*);
+    };
+
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $this:ident ($field_vis:vis $inner:ty) $($rest:tt)*
+    ) => {
+        $crate::newtype!(@struct [$(#[$attr])*] $vis $this ($field_vis $inner));
+        $crate::newtype!($($rest)*);
+    };
+
+    (@struct [$(#[$attr:meta])*] $vis:vis $this:ident ($field_vis:vis $inner:ty)) => {
+        $(#[$attr])*
+        $vis struct $this($field_vis $inner);
+    };
+
+    ($($input:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "expected declarations of the form `struct Name(Type)`, ",
+            "optionally followed by `impl { Trait, ... }`",
+        ));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::from_over_into)]
+
+    use alloc::{
+        string::{String, ToString as _},
+        vec,
+        vec::Vec,
+    };
+    use core::{
+        borrow::Borrow,
+        fmt,
+        ops::{Deref, DerefMut},
+        str::FromStr,
+    };
+
+    #[derive(Debug)]
+    struct UserId(String);
+    impl_newtype!(UserId(String): From, Into, AsRef<str>, Deref, Display, FromStr, Borrow<str>);
+
+    static_assertions::assert_impl_all!(
+        UserId:
+        // impls
+        From<String>,
+        Into<String>,
+        AsRef<str>,
+        Deref<Target = String>,
+        fmt::Display,
+        FromStr,
+        Borrow<str>,
+    );
+    static_assertions::assert_not_impl_any!(UserId: AsRef<String>, DerefMut, Borrow<String>);
+
+    struct Bytes(Vec<u8>);
+    impl_newtype!(
+        #[automatically_derived]
+        Bytes(Vec<u8>): AsRef, AsMut<[u8]>, Borrow, Deref, DerefMut,
+    );
+
+    static_assertions::assert_impl_all!(
+        Bytes:
+        // impls
+        AsRef<Vec<u8>>,
+        AsMut<[u8]>,
+        Borrow<Vec<u8>>,
+        Deref<Target = Vec<u8>>,
+        DerefMut,
+    );
+
+    struct Tagged<T>(T);
+    impl_newtype!(<T> in Tagged<T>(T): From, Display, FromStr, Borrow<str>);
+
+    static_assertions::assert_impl_all!(
+        Tagged<String>:
+        // impls
+        From<String>,
+        fmt::Display,
+        FromStr,
+        Borrow<str>,
+    );
+    static_assertions::assert_not_impl_any!(Tagged<Vec<u8>>: fmt::Display, FromStr, Borrow<str>);
+
+    struct Buffer<'a, T, const N: usize>(&'a [T; N]);
+    impl_newtype!(<'a, T, const N: usize> in Buffer<'a, T, N>(&'a [T; N]): AsRef<[T]>, Deref);
+
+    struct UserName(String);
+    struct OrgName(String);
+    impl_newtype! {
+        UserName(String): From, Display;
+        OrgName(String): From, Display;
+    }
+
+    newtype! {
+        #[derive(Debug, Clone, PartialEq)]
+        pub(crate) struct Email(String) impl { Deref, Display, FromStr, From }
+
+        /// Number of attempts made.
+        struct Attempts(pub u32) impl { From, Into, AsRef, }
+
+        struct Opaque(u8);
+        struct Unimplemented(u8) impl {}
+    }
+
+    static_assertions::assert_impl_all!(Email: Clone, Deref<Target = String>, FromStr);
+    static_assertions::assert_not_impl_any!(Opaque: From<u8>);
+    static_assertions::assert_not_impl_any!(Unimplemented: From<u8>);
+
+    #[test]
+    fn newtype() {
+        let id = "alice".parse::<UserId>().unwrap();
+        assert_eq!(id.len(), 5);
+        assert_eq!(id.to_string(), "alice");
+        assert_eq!(Borrow::<str>::borrow(&id), "alice");
+        assert_eq!(id.as_ref(), "alice");
+        assert_eq!(Into::<String>::into(id), "alice");
+
+        let mut bytes = Bytes(vec![1, 2]);
+        bytes.push(3);
+        bytes.as_mut()[0] = 0;
+        assert_eq!(bytes.as_ref(), &[0, 2, 3]);
+
+        assert_eq!(Tagged::<u8>::from_str("7").unwrap().to_string(), "7");
+        assert_eq!(Buffer(&[1, 2]).as_ref(), &[1, 2]);
+        assert_eq!(UserName::from("bob".to_string()).to_string(), "bob");
+        assert_eq!(OrgName::from("acme".to_string()).to_string(), "acme");
+    }
+
+    #[test]
+    fn declare() {
+        let email = "hi@example.com".parse::<Email>().unwrap();
+        assert_eq!(email, Email::from("hi@example.com".to_string()));
+        assert_eq!(email.len(), 14);
+        assert_eq!(email.to_string(), "hi@example.com");
+
+        let attempts = Attempts::from(3);
+        assert_eq!(attempts.as_ref(), &3);
+        assert_eq!(Into::<u32>::into(Attempts(3)), 3);
+        assert_eq!(Opaque(1).0, Unimplemented(1).0);
+    }
+}
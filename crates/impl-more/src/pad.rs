@@ -0,0 +1,87 @@
+use core::fmt::{self, Write as _};
+
+/// Writes formatted arguments to `fmt`, respecting its width, fill, alignment, and precision.
+///
+/// This behaves like [`Formatter::pad`] but for [`Arguments`], without allocating. When padding or
+/// truncation is requested, the arguments are formatted twice: once to measure and once to write.
+///
+/// [`Formatter::pad`]: fmt::Formatter::pad
+/// [`Arguments`]: fmt::Arguments
+#[doc(hidden)]
+pub fn pad_args(fmt: &mut fmt::Formatter<'_>, args: fmt::Arguments<'_>) -> fmt::Result {
+    if fmt.width().is_none() && fmt.precision().is_none() {
+        return fmt.write_fmt(args);
+    }
+
+    if let Some(s) = args.as_str() {
+        return fmt.pad(s);
+    }
+
+    let mut count = CharCount(0);
+    fmt::write(&mut count, args)?;
+
+    let len = match fmt.precision() {
+        Some(max) => count.0.min(max),
+        None => count.0,
+    };
+
+    let padding = fmt.width().unwrap_or(0).saturating_sub(len);
+
+    // strings are left-aligned by default
+    let (pre, post) = match fmt.align() {
+        None | Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, (padding + 1) / 2),
+    };
+
+    let fill = fmt.fill();
+
+    for _ in 0..pre {
+        fmt.write_char(fill)?;
+    }
+
+    fmt::write(
+        &mut Truncate {
+            fmt: &mut *fmt,
+            remaining: len,
+        },
+        args,
+    )?;
+
+    for _ in 0..post {
+        fmt.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+/// Counts the characters written to it.
+struct CharCount(usize);
+
+impl fmt::Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Forwards at most `remaining` characters to the wrapped formatter.
+struct Truncate<'a, 'b> {
+    fmt: &'a mut fmt::Formatter<'b>,
+    remaining: usize,
+}
+
+impl fmt::Write for Truncate<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match s.char_indices().nth(self.remaining) {
+            Some((end, _)) => {
+                self.remaining = 0;
+                self.fmt.write_str(&s[..end])
+            }
+            None => {
+                self.remaining -= s.chars().count();
+                self.fmt.write_str(s)
+            }
+        }
+    }
+}
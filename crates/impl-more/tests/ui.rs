@@ -0,0 +1,11 @@
+//! UI tests for diagnostics emitted by malformed macro invocations.
+//!
+//! Expected output is only kept for recent stable compilers, which trybuild also requires.
+
+#[rustversion::since(1.88)]
+#[rustversion::attr(not(stable), ignore)]
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,5 @@
+struct Foo;
+
+impl_more::impl_display!(Foo, "foo");
+
+fn main() {}
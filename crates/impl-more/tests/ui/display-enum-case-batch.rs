@@ -0,0 +1,15 @@
+enum Foo {
+    Bar,
+}
+
+enum Color {
+    DarkRed,
+    LightBlue,
+}
+
+impl_more::impl_display_enum! {
+    Foo: Bar => "bar";
+    Color: case = kebab; DarkRed, LightBlue;
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+enum Foo {
+    Bar,
+    Qux,
+}
+
+impl_more::impl_display_enum!(Foo: case = title, Bar, Qux);
+
+fn main() {}
@@ -0,0 +1,8 @@
+enum Foo {
+    Bar,
+    Qux,
+}
+
+impl_more::impl_display_enum!(Foo, Bar => "bar", Qux => "qux");
+
+fn main() {}
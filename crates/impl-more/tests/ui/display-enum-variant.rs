@@ -0,0 +1,8 @@
+enum Foo {
+    Bar(u64, u64),
+    Qux { msg: &'static str },
+}
+
+impl_more::impl_display_enum!(Foo: Bar(x, y) => "{x}, {y}", Qux { msg } => msg);
+
+fn main() {}
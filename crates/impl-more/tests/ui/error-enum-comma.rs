@@ -0,0 +1,9 @@
+#[derive(Debug)]
+enum Error {
+    Io(std::io::Error),
+}
+
+impl_more::impl_display_enum!(Error: Io(err) => "{err}");
+impl_more::impl_error_enum!(Error, Io(err) => err);
+
+fn main() {}
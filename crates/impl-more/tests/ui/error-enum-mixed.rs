@@ -0,0 +1,10 @@
+#[derive(Debug)]
+enum Error {
+    Io(std::io::Error),
+    Parse(std::num::ParseIntError),
+}
+
+impl_more::impl_display_enum!(Error: Io(err) => "{err}", Parse(err) => "{err}");
+impl_more::impl_error_enum!(Error: Io(err) => err, Parse(err) => "invalid number");
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(Debug)]
+enum Error {
+    Io(std::io::Error),
+    Other,
+}
+
+impl_more::impl_error_enum!(Error: Io(err) => "i/o error", source = transparent err, Other => "other");
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[derive(Debug)]
+enum Error {
+    Io(std::io::Error),
+    Other,
+}
+
+impl_more::impl_display_enum!(Error: Io(err) => "{err}", Other => "other");
+impl_more::impl_error_enum!(Error: Io(err) => err, Other => None);
+
+fn main() {}
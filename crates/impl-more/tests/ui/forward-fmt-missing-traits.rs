@@ -0,0 +1,7 @@
+struct Foo {
+    inner: u64,
+}
+
+impl_more::forward_fmt!(Foo => inner);
+
+fn main() {}
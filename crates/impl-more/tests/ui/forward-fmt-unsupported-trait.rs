@@ -0,0 +1,5 @@
+struct Hash(u64);
+
+impl_more::forward_fmt!(Hash => Display, Write);
+
+fn main() {}
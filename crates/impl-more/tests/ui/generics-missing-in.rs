@@ -0,0 +1,5 @@
+struct Foo<T>(T);
+
+impl_more::impl_deref!(<T> Foo<T> => T);
+
+fn main() {}
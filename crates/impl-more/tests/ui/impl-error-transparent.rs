@@ -0,0 +1,8 @@
+#[derive(Debug)]
+struct Error {
+    inner: std::io::Error,
+}
+
+impl_more::impl_error!(Error: "i/o error", source = transparent(inner));
+
+fn main() {}
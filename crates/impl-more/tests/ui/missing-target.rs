@@ -0,0 +1,8 @@
+struct Foo(String);
+struct Bar<T>(T);
+
+impl_more::impl_as_ref!(Foo);
+impl_more::impl_deref!(<T> in Bar<T>);
+impl_more::impl_from! { String => Foo; u8 }
+
+fn main() {}
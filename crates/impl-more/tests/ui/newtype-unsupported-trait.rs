@@ -0,0 +1,5 @@
+struct Foo(u8);
+
+impl_more::impl_newtype!(Foo(u8): From, Clone);
+
+fn main() {}
@@ -25,6 +25,23 @@ struct Bar {
 impl_more::forward_as_ref_and_mut!(Bar => inner: str);
 impl_more::forward_display!(Bar => inner);
 
+#[derive(Debug, Clone)]
+struct Hash(u64);
+
+impl_more::forward_fmt!(Hash => LowerHex, UpperHex, Binary, Octal);
+
+#[derive(Debug, Clone)]
+struct Meters(f64);
+
+impl_more::forward_display!(Meters);
+impl_more::forward_lower_exp!(Meters);
+impl_more::forward_upper_exp!(Meters);
+
+struct Handle<'a>(&'a u8);
+
+impl_more::forward_debug!(<'a> in Handle<'a>);
+impl_more::forward_pointer!(<'a> in Handle<'a>);
+
 #[derive(Debug)]
 enum FooEnum {
     Bar,
@@ -33,6 +50,20 @@ enum FooEnum {
 
 impl_more::impl_display_enum!(FooEnum: Bar => "bar", Qux => "qux");
 
+#[derive(Debug)]
+enum Event {
+    Tick,
+    Key(char),
+    Move { x: i32, y: i32 },
+}
+
+impl_more::impl_display_enum!(
+    Event:
+    Tick => "tick",
+    Key(key) => "key {key}",
+    Move { x, y } => "{x}, {y}",
+);
+
 #[derive(Debug, Clone)]
 struct Baz<T> {
     inner: T,
@@ -60,7 +91,73 @@ enum Errors {
 impl_more::impl_display!(Errors: "wrapped");
 impl_more::impl_error_enum!(Errors: Wrapped(err) => err);
 
+#[derive(Debug)]
+struct Failed {
+    code: u8,
+    cause: LeafErr,
+}
+
+impl_more::impl_error!(Failed { code, .. }: "failed with {code}", source = cause: LeafErr);
+
+#[derive(Debug)]
+enum Failures {
+    Leaf(LeafErr),
+    Code(u8),
+}
+
+impl_more::impl_error_enum!(Failures: Leaf(err) => "leaf", source = err, Code(code) => "{code}");
+
 #[derive(Debug, Clone)]
 struct Checked(bool);
 
 impl_more::impl_newtype_from_into!(Checked [<=>] bool);
+
+#[derive(Debug, Clone)]
+struct Name<'a>(&'a str);
+
+impl_more::impl_as_ref!(Name<'a> => &'a str);
+impl_more::forward_as_ref!(<'a> in Name<'a> => str);
+impl_more::impl_deref!(<'a> in Name<'a> => str);
+impl_more::impl_from!(<'a> in &'a str => Name<'a>);
+impl_more::impl_into!(<'a> in Name<'a> => &'a str);
+impl_more::forward_display!(<'a> in Name<'a>);
+
+#[derive(Debug, Clone)]
+struct View<'a, T>(&'a [T]);
+
+impl_more::forward_as_ref!(<'a, T> in View<'a, T> => [T]);
+impl_more::impl_deref!(<'a, T> in View<'a, T> => [T]);
+impl_more::impl_newtype_from_into!(<'a, T> in View<'a, T> [<=>] &'a [T]);
+
+#[derive(Debug)]
+struct BorrowedErr<'a> {
+    msg: &'a str,
+}
+
+impl_more::forward_display!(<'a> in BorrowedErr<'a> => msg);
+impl_more::impl_leaf_error!(<'a> in BorrowedErr<'a>);
+
+#[derive(Debug, Clone)]
+struct Frame<const N: usize>([u8; N]);
+
+impl_more::impl_deref!(<const N: usize> in Frame<N> => [u8; N]);
+impl_more::impl_deref_mut!(<const N: usize> in Frame<N>);
+impl_more::forward_as_ref!(<const N: usize> in Frame<N> => [u8]);
+impl_more::impl_newtype_from_into!(<const N: usize> in Frame<N> [<=>] [u8; N]);
+
+#[derive(Debug, Clone)]
+struct Packet<T, const N: usize> {
+    items: [T; N],
+}
+
+impl_more::forward_as_ref_and_mut!(<T, const N: usize> in Packet<T, N> => items: [T]);
+impl_more::impl_from!(<T, const N: usize> in [T; N] => Packet<T, N> : items);
+
+struct Code(u16);
+
+impl_more::impl_newtype!(Code(u16): From, Into, AsRef, Borrow, Deref, Display, FromStr);
+
+impl_more::newtype! {
+    #[derive(Debug, Clone)]
+    struct Port(u16) impl { From, Display, FromStr }
+}